@@ -0,0 +1,215 @@
+//! # chrono Interoperability
+//!
+//! This module provides conversions between the [`DateTime`] type used by
+//! this driver and [`chrono::NaiveDateTime`], along with convenience methods
+//! on [`Ds1307`] to read and set the clock using chrono types.
+//!
+//! Enable with the `chrono` feature.
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use rtc_hal::{
+    datetime::{DateTime, DateTimeError},
+    rtc::Rtc,
+};
+
+use crate::{
+    Ds1307,
+    datetime::{datetime_from_fields, validate_for_ds1307},
+    error::Error,
+};
+
+/// Convert a [`DateTime`] into a [`chrono::NaiveDateTime`].
+///
+/// This conversion cannot fail since every valid [`DateTime`] is
+/// representable by chrono.
+pub fn to_naive_datetime(datetime: &DateTime) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(
+        datetime.year() as i32,
+        datetime.month() as u32,
+        datetime.day_of_month() as u32,
+    )
+    .and_then(|date| {
+        date.and_hms_opt(
+            datetime.hour() as u32,
+            datetime.minute() as u32,
+            datetime.second() as u32,
+        )
+    })
+    // DateTime is validated on construction
+    .expect("DateTime is always a valid calendar date and time")
+}
+
+/// Convert a [`chrono::NaiveDateTime`] into a [`DateTime`] within the DS1307 range.
+///
+/// Returns [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the year
+/// is outside 2000-2099. Sub-second precision is discarded.
+///
+/// [`Ds1307::set_datetime_chrono`] checks the driver's own century window
+/// instead, see [`set_century_base`](Ds1307::set_century_base).
+pub fn from_naive_datetime<E>(datetime: &NaiveDateTime) -> Result<DateTime, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    let datetime = naive_to_datetime(datetime).map_err(Error::DateTime)?;
+    validate_for_ds1307(&datetime)?;
+
+    Ok(datetime)
+}
+
+/// Convert a [`chrono::NaiveDateTime`] into a [`DateTime`] without checking
+/// the DS1307 century window.
+fn naive_to_datetime(datetime: &NaiveDateTime) -> Result<DateTime, DateTimeError> {
+    datetime_from_fields(
+        datetime.year(),
+        datetime.month() as u8,
+        datetime.day() as u8,
+        datetime.hour() as u8,
+        datetime.minute() as u8,
+        datetime.second() as u8,
+    )
+}
+
+impl<I2C, E> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Read the current date and time as a [`chrono::NaiveDateTime`].
+    pub fn get_datetime_chrono(&mut self) -> Result<NaiveDateTime, Error<E>> {
+        let datetime = self.get_datetime()?;
+        Ok(to_naive_datetime(&datetime))
+    }
+
+    /// Set the current date and time from a [`chrono::NaiveDateTime`].
+    ///
+    /// Returns [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the year
+    /// is outside the century window (2000-2099 by default, see
+    /// [`set_century_base`](Self::set_century_base)), before any bus access.
+    pub fn set_datetime_chrono(&mut self, datetime: &NaiveDateTime) -> Result<(), Error<E>> {
+        let datetime = naive_to_datetime(datetime).map_err(Error::DateTime)?;
+        self.set_datetime(&datetime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::Register;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+    fn naive(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, min, sec)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_to_naive_datetime() {
+        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
+        assert_eq!(to_naive_datetime(&datetime), naive(2025, 8, 27, 15, 30, 45));
+    }
+
+    #[test]
+    fn test_from_naive_datetime() {
+        let datetime = from_naive_datetime::<()>(&naive(2024, 2, 29, 23, 59, 59)).unwrap();
+        assert_eq!(datetime, DateTime::new(2024, 2, 29, 23, 59, 59).unwrap());
+    }
+
+    #[test]
+    fn test_from_naive_datetime_out_of_range() {
+        for year in [1969, 1999, 2100, 70_000] {
+            let result = from_naive_datetime::<()>(&naive(year, 1, 1, 0, 0, 0));
+            assert!(matches!(
+                result,
+                Err(Error::DateTime(DateTimeError::InvalidYear))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_get_datetime_chrono() {
+        let data = [0x25, 0x59, 0x23, 0x04, 0x15, 0x08, 0x23];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        let datetime = ds1307.get_datetime_chrono().unwrap();
+        assert_eq!(datetime, naive(2023, 8, 15, 23, 59, 25));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_chrono() {
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x45,
+                0x30,
+                0x15,
+                0x04,
+                0x27,
+                0x08,
+                0x25,
+            ],
+        )];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        ds1307
+            .set_datetime_chrono(&naive(2025, 8, 27, 15, 30, 45))
+            .unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_chrono_invalid_year() {
+        let mut ds1307 = Ds1307::new(I2cMock::new(&[]));
+
+        let result = ds1307.set_datetime_chrono(&naive(2100, 1, 1, 0, 0, 0));
+        assert!(matches!(
+            result,
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_chrono_custom_century_base() {
+        // 1999-12-31 is a Friday (6)
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x59,
+                0x59,
+                0x23,
+                0x06,
+                0x31,
+                0x12,
+                0x29,
+            ],
+        )];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        ds1307.set_century_base(1970).unwrap();
+
+        ds1307
+            .set_datetime_chrono(&naive(1999, 12, 31, 23, 59, 59))
+            .unwrap();
+
+        // 2070 falls outside the 1970-2069 window: no write
+        let result = ds1307.set_datetime_chrono(&naive(2070, 1, 1, 0, 0, 0));
+        assert!(matches!(
+            result,
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+}
@@ -0,0 +1,306 @@
+//! # Device Configuration
+//!
+//! This module provides [`Ds1307Config`], a builder describing the desired
+//! oscillator state, SQW/OUT pin configuration and optional initial date and
+//! time, and [`Ds1307::configure`] to apply it in one call.
+//!
+//! Control register changes are combined into a single read-modify-write, and
+//! an initial datetime is written in one burst that also carries the desired
+//! Clock Halt (CH) state.
+
+use rtc_hal::{datetime::DateTime, square_wave::SquareWaveFreq};
+
+use crate::{
+    Ds1307,
+    datetime::HourFormat,
+    error::Error,
+    registers::{CH_BIT, OUT_BIT, RS_MASK, Register, SQWE_BIT},
+    square_wave::freq_to_bits,
+};
+
+/// Desired state of the SQW/OUT pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputConfig {
+    /// Square wave output at the given frequency
+    SquareWave(SquareWaveFreq),
+    /// Static output level (`true` = high), square wave disabled
+    Level(bool),
+}
+
+/// DS1307 configuration builder
+///
+/// Settings left unspecified are not touched by [`Ds1307::configure`].
+///
+/// # Example
+/// ```ignore
+/// let config = Ds1307Config::new()
+///     .start_clock()
+///     .square_wave(SquareWaveFreq::Hz1)
+///     .datetime(DateTime::new(2025, 8, 21, 14, 30, 0).unwrap());
+/// rtc.configure(&config)?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Ds1307Config {
+    clock_running: Option<bool>,
+    output: Option<OutputConfig>,
+    datetime: Option<DateTime>,
+}
+
+impl Ds1307Config {
+    /// Create an empty configuration that leaves the device unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run the oscillator (clear the CH bit)
+    pub fn start_clock(mut self) -> Self {
+        self.clock_running = Some(true);
+        self
+    }
+
+    /// Halt the oscillator (set the CH bit)
+    pub fn halt_clock(mut self) -> Self {
+        self.clock_running = Some(false);
+        self
+    }
+
+    /// Enable the square wave output at the given frequency
+    ///
+    /// Overrides any previous [`output_high`](Self::output_high) or
+    /// [`output_low`](Self::output_low).
+    pub fn square_wave(mut self, freq: SquareWaveFreq) -> Self {
+        self.output = Some(OutputConfig::SquareWave(freq));
+        self
+    }
+
+    /// Disable the square wave and drive the output pin high
+    pub fn output_high(mut self) -> Self {
+        self.output = Some(OutputConfig::Level(true));
+        self
+    }
+
+    /// Disable the square wave and drive the output pin low
+    pub fn output_low(mut self) -> Self {
+        self.output = Some(OutputConfig::Level(false));
+        self
+    }
+
+    /// Set the date and time (in 24-hour mode)
+    pub fn datetime(mut self, datetime: DateTime) -> Self {
+        self.datetime = Some(datetime);
+        self
+    }
+}
+
+impl<I2C, E> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Apply a [`Ds1307Config`] with the minimum number of I2C writes.
+    ///
+    /// All settings are validated before any bus traffic. The control register
+    /// is updated with at most one read and one write. If a datetime is given,
+    /// it is written in a single burst with the CH bit set according to the
+    /// oscillator setting (running if unspecified, like
+    /// [`Rtc::set_datetime`](rtc_hal::rtc::Rtc::set_datetime)). Otherwise the
+    /// oscillator setting is applied with a read-modify-write of the seconds
+    /// register.
+    pub fn configure(&mut self, config: &Ds1307Config) -> Result<(), Error<E>> {
+        // Validate everything up front
+        let output = match config.output {
+            Some(OutputConfig::SquareWave(freq)) => Some((true, freq_to_bits(freq)?)),
+            Some(OutputConfig::Level(high)) => Some((false, if high { OUT_BIT } else { 0 })),
+            None => None,
+        };
+        let registers = match &config.datetime {
+            Some(datetime) => Some(self.encode_for_device(datetime, HourFormat::TwentyFourHour)?),
+            None => None,
+        };
+
+        if let Some((square_wave, bits)) = output {
+            let current = self.read_register(Register::Control)?;
+            let new_value = if square_wave {
+                // Enable square wave at the requested rate, disable OUT
+                (current & !(RS_MASK | OUT_BIT)) | SQWE_BIT | bits
+            } else {
+                // Disable square wave and drive OUT
+                (current & !(SQWE_BIT | OUT_BIT)) | bits
+            };
+
+            if new_value != current {
+                self.write_register(Register::Control, new_value)?;
+            }
+        }
+
+        match (registers, config.clock_running) {
+            (Some(registers), running) => {
+                let mut data = [0u8; 8];
+                data[0] = Register::Seconds.addr();
+                data[1..].copy_from_slice(&registers);
+                if running == Some(false) {
+                    data[1] |= CH_BIT;
+                }
+
+                self.write_raw_bytes(&data)?;
+                self.follow_hour_format(HourFormat::TwentyFourHour);
+            }
+            (None, Some(true)) => {
+                self.clear_register_bits(Register::Seconds, CH_BIT)?;
+            }
+            (None, Some(false)) => {
+                self.set_register_bits(Register::Seconds, CH_BIT)?;
+            }
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+    use rtc_hal::datetime::DateTimeError;
+
+    const DS1307_ADDR: u8 = 0x68;
+
+    #[test]
+    fn test_configure_empty() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.configure(&Ds1307Config::new()).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_all() {
+        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![OUT_BIT]),
+            I2cTransaction::write(
+                DS1307_ADDR,
+                vec![Register::Control.addr(), SQWE_BIT | 0b0000_0011],
+            ),
+            I2cTransaction::write(
+                DS1307_ADDR,
+                vec![
+                    Register::Seconds.addr(),
+                    0x45,
+                    0x30,
+                    0x15,
+                    0x04,
+                    0x27,
+                    0x08,
+                    0x25,
+                ],
+            ),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let config = Ds1307Config::new()
+            .start_clock()
+            .square_wave(SquareWaveFreq::Hz32768)
+            .datetime(datetime);
+        assert!(ds1307.configure(&config).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_datetime_halted() {
+        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
+        let expectations = vec![I2cTransaction::write(
+            DS1307_ADDR,
+            vec![
+                Register::Seconds.addr(),
+                CH_BIT | 0x45,
+                0x30,
+                0x15,
+                0x04,
+                0x27,
+                0x08,
+                0x25,
+            ],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let config = Ds1307Config::new().halt_clock().datetime(datetime);
+        assert!(ds1307.configure(&config).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_output_and_clock_without_datetime() {
+        let expectations = vec![
+            // Control register already has OUT low and SQWE clear: no write
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x03]),
+            // Start clock via read-modify-write
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![CH_BIT]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Seconds.addr(), 0x00]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let config = Ds1307Config::new().output_low().start_clock();
+        assert!(ds1307.configure(&config).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_output_high() {
+        let expectations = vec![
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Control.addr()],
+                vec![SQWE_BIT | 0x01],
+            ),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), OUT_BIT | 0x01]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.configure(&Ds1307Config::new().output_high()).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_validates_before_bus_access() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let config = Ds1307Config::new().square_wave(SquareWaveFreq::Hz1024);
+        assert!(matches!(
+            ds1307.configure(&config),
+            Err(Error::UnsupportedSqwFrequency)
+        ));
+
+        let config = Ds1307Config::new()
+            .output_low()
+            .datetime(DateTime::new(2100, 1, 1, 0, 0, 0).unwrap());
+        assert!(matches!(
+            ds1307.configure(&config),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+}
@@ -12,6 +12,8 @@ pub use rtc_hal::control::RtcPowerControl;
 
 use crate::{
     Ds1307,
+    ds1307::WriteOutcome,
+    error::Error,
     registers::{CH_BIT, Register},
 };
 
@@ -23,16 +25,45 @@ where
     /// Start or resume the RTC oscillator so that timekeeping can continue.
     /// This operation is idempotent - calling it when already running has no effect.
     fn start_clock(&mut self) -> Result<(), Self::Error> {
-        // Clear Clock Halt (CH) bit in seconds register to start oscillator
-        self.clear_register_bits(Register::Seconds, CH_BIT)
+        self.start_clock_with_outcome().map(|_| ())
     }
 
     /// Halt the RTC oscillator, pausing timekeeping until restarted.
     /// This operation is idempotent - calling it when already halted has no effect.
     fn halt_clock(&mut self) -> Result<(), Self::Error> {
+        self.halt_clock_with_outcome().map(|_| ())
+    }
+}
+
+impl<I2C, E> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Start the oscillator, reporting whether the seconds register was written.
+    ///
+    /// Returns [`WriteOutcome::Unchanged`] if the clock was already running.
+    pub fn start_clock_with_outcome(&mut self) -> Result<WriteOutcome, Error<E>> {
+        // Clear Clock Halt (CH) bit in seconds register to start oscillator
+        self.clear_register_bits(Register::Seconds, CH_BIT)
+    }
+
+    /// Halt the oscillator, reporting whether the seconds register was written.
+    ///
+    /// Returns [`WriteOutcome::Unchanged`] if the clock was already halted.
+    pub fn halt_clock_with_outcome(&mut self) -> Result<WriteOutcome, Error<E>> {
         // Set Clock Halt (CH) bit in seconds register to stop oscillator
         self.set_register_bits(Register::Seconds, CH_BIT)
     }
+
+    /// Read the Clock Halt (CH) bit without decoding the time.
+    ///
+    /// Reads only the seconds register and returns `true` if the oscillator is
+    /// halted.
+    pub fn read_clock_halt(&mut self) -> Result<bool, Error<E>> {
+        let seconds = self.read_register(Register::Seconds)?;
+        Ok((seconds & CH_BIT) != 0)
+    }
 }
 
 #[cfg(test)]
@@ -363,4 +394,48 @@ mod tests {
 
         i2c_mock.done();
     }
+
+    #[test]
+    fn test_read_clock_halt() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0xD9]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x59]),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert!(ds1307.read_clock_halt().unwrap());
+        assert!(!ds1307.read_clock_halt().unwrap());
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_start_and_halt_clock_with_outcome() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x80]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Seconds.addr(), 0x00]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x00]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x80]),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert_eq!(
+            ds1307.start_clock_with_outcome().unwrap(),
+            WriteOutcome::Changed
+        );
+        assert_eq!(
+            ds1307.start_clock_with_outcome().unwrap(),
+            WriteOutcome::Unchanged
+        );
+        assert_eq!(
+            ds1307.halt_clock_with_outcome().unwrap(),
+            WriteOutcome::Unchanged
+        );
+
+        i2c_mock.done();
+    }
 }
@@ -3,9 +3,546 @@
 //! This module provides an implementation of the [`Rtc`] trait for the
 //! DS1307 real-time clock (RTC).
 
-use rtc_hal::{bcd, datetime::DateTimeError, rtc::Rtc};
+use rtc_hal::{
+    bcd,
+    datetime::{DateTimeError, Weekday},
+    rtc::Rtc,
+};
 
-use crate::{Ds1307, registers::Register};
+use crate::{
+    Ds1307,
+    error::Error,
+    registers::{CH_BIT, HOUR_12_BIT, PM_BIT, Register},
+};
+
+/// Hour format used by the DS1307 hours register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HourFormat {
+    /// 24-hour mode (bit 6 of the hours register cleared)
+    TwentyFourHour,
+    /// 12-hour mode with AM/PM (bit 6 of the hours register set)
+    TwelveHour,
+}
+
+/// Undecoded snapshot of the seven DS1307 timekeeping registers (0x00-0x06)
+///
+/// Both the [`Display`](core::fmt::Display) and, with the `defmt` feature,
+/// `defmt::Format` output show the BCD fields as written on the chip (so
+/// invalid values remain visible) together with the CH, 12/24-hour and AM/PM
+/// flags, e.g. `yy=24 mm=12 dd=31 hh=11 mi=59 ss=58 dow=03 CH=0 12h PM`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawDateTime(pub [u8; 7]);
+
+impl RawDateTime {
+    /// Clock Halt bit of the seconds register.
+    pub fn is_halted(&self) -> bool {
+        (self.0[0] & CH_BIT) != 0
+    }
+
+    /// Hour format selected by bit 6 of the hours register.
+    pub fn hour_format(&self) -> HourFormat {
+        hour_format(self.0[2])
+    }
+
+    /// AM/PM flag of the hours register, or `None` in 24-hour mode.
+    pub fn is_pm(&self) -> Option<bool> {
+        match self.hour_format() {
+            HourFormat::TwelveHour => Some((self.0[2] & PM_BIT) != 0),
+            HourFormat::TwentyFourHour => None,
+        }
+    }
+
+    /// Hours register with the mode and AM/PM bits masked off.
+    fn hour_bits(&self) -> u8 {
+        match self.hour_format() {
+            HourFormat::TwelveHour => self.0[2] & 0b0001_1111,
+            HourFormat::TwentyFourHour => self.0[2] & 0b0011_1111,
+        }
+    }
+
+    /// Mode and AM/PM suffix used by the `Display` and `defmt` output.
+    fn mode_str(&self) -> &'static str {
+        match self.is_pm() {
+            None => "24h",
+            Some(false) => "12h AM",
+            Some(true) => "12h PM",
+        }
+    }
+
+    /// Decode the snapshot into a [`DateTime`](rtc_hal::datetime::DateTime),
+    /// see [`decode_datetime`].
+    pub fn decode<E>(&self) -> Result<rtc_hal::datetime::DateTime, Error<E>>
+    where
+        E: core::fmt::Debug,
+    {
+        decode_datetime(&self.0)
+    }
+}
+
+impl core::fmt::Display for RawDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "yy={:02x} mm={:02x} dd={:02x} hh={:02x} mi={:02x} ss={:02x} dow={:02x} CH={} {}",
+            self.0[6],
+            self.0[5],
+            self.0[4],
+            self.hour_bits(),
+            self.0[1],
+            self.0[0] & !CH_BIT,
+            self.0[3],
+            self.is_halted() as u8,
+            self.mode_str(),
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for RawDateTime {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "yy={=u8:02x} mm={=u8:02x} dd={=u8:02x} hh={=u8:02x} mi={=u8:02x} ss={=u8:02x} dow={=u8:02x} CH={=u8} {=str}",
+            self.0[6],
+            self.0[5],
+            self.0[4],
+            self.hour_bits(),
+            self.0[1],
+            self.0[0] & !CH_BIT,
+            self.0[3],
+            self.is_halted() as u8,
+            self.mode_str(),
+        )
+    }
+}
+
+/// Default first year of the century window mapped onto the two-digit year register
+pub const DEFAULT_CENTURY_BASE: u16 = 2000;
+
+/// Years the DS1307 can store with the default century base
+pub const DS1307_YEAR_RANGE: core::ops::RangeInclusive<u16> =
+    DEFAULT_CENTURY_BASE..=DEFAULT_CENTURY_BASE + 99;
+
+/// Check that `year` falls in the century window starting at `century_base`.
+fn validate_year(year: u16, century_base: u16) -> Result<(), DateTimeError> {
+    if !(century_base..=century_base + 99).contains(&year) {
+        // DS1307 only allow this date range
+        return Err(DateTimeError::InvalidYear);
+    }
+
+    Ok(())
+}
+
+/// Check that a [`DateTime`](rtc_hal::datetime::DateTime) can be stored on the
+/// DS1307, without any bus access.
+///
+/// Returns [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the year is
+/// outside [`DS1307_YEAR_RANGE`]. This is the check [`Rtc::set_datetime`]
+/// performs before writing; for drivers with a custom century base use
+/// [`Ds1307::validate_datetime`] instead.
+pub fn validate_for_ds1307<E>(datetime: &rtc_hal::datetime::DateTime) -> Result<(), Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    validate_year(datetime.year(), DEFAULT_CENTURY_BASE).map_err(Error::DateTime)
+}
+
+/// Build a [`DateTime`](rtc_hal::datetime::DateTime) from the calendar fields
+/// of another date/time library.
+///
+/// Only the range supported by `DateTime` itself is checked, not the DS1307
+/// century window; the `year` is rejected with [`DateTimeError::InvalidYear`]
+/// if it does not fit.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn datetime_from_fields(
+    year: i32,
+    month: u8,
+    day_of_month: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+) -> Result<rtc_hal::datetime::DateTime, DateTimeError> {
+    let year = u16::try_from(year).map_err(|_| DateTimeError::InvalidYear)?;
+
+    rtc_hal::datetime::DateTime::new(year, month, day_of_month, hour, minute, second)
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Number of days since 1970-01-01 for the given civil date.
+///
+/// Based on Howard Hinnant's `days_from_civil` algorithm, which accounts for
+/// leap years using the proleptic Gregorian calendar.
+fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let month = month as i64;
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400; // [0, 399]
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+/// Civil date `(year, month, day)` for the given number of days since 1970-01-01.
+///
+/// Inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8; // [1, 12]
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Convert a [`DateTime`](rtc_hal::datetime::DateTime) into seconds since the Unix epoch.
+pub(crate) fn datetime_to_unix(datetime: &rtc_hal::datetime::DateTime) -> i64 {
+    let days = days_from_civil(datetime.year(), datetime.month(), datetime.day_of_month());
+    days * SECONDS_PER_DAY
+        + datetime.hour() as i64 * 3600
+        + datetime.minute() as i64 * 60
+        + datetime.second() as i64
+}
+
+/// Convert seconds since the Unix epoch into a [`DateTime`](rtc_hal::datetime::DateTime).
+///
+/// Returns [`DateTimeError::InvalidYear`] if the timestamp falls outside the
+/// century window starting at `century_base`.
+pub(crate) fn unix_to_datetime(
+    timestamp: i64,
+    century_base: u16,
+) -> Result<rtc_hal::datetime::DateTime, DateTimeError> {
+    let min = days_from_civil(century_base, 1, 1) * SECONDS_PER_DAY;
+    let end = days_from_civil(century_base + 100, 1, 1) * SECONDS_PER_DAY;
+    if !(min..end).contains(&timestamp) {
+        // DS1307 only allow this date range
+        return Err(DateTimeError::InvalidYear);
+    }
+
+    let days = timestamp.div_euclid(SECONDS_PER_DAY);
+    let secs_of_day = timestamp.rem_euclid(SECONDS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+
+    rtc_hal::datetime::DateTime::new(
+        year as u16,
+        month,
+        day,
+        (secs_of_day / 3600) as u8,
+        ((secs_of_day % 3600) / 60) as u8,
+        (secs_of_day % 60) as u8,
+    )
+}
+
+/// Decode a BCD register value, checking that both nibbles are decimal digits
+/// and that the decoded value is within `min..=max`.
+///
+/// Returns [`Error::InvalidBcd`] naming `register` otherwise.
+fn decode_bcd<E>(raw: u8, register: Register, min: u8, max: u8) -> Result<u8, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    if (raw >> 4) > 9 || (raw & 0x0F) > 9 {
+        return Err(Error::InvalidBcd { register });
+    }
+
+    let value = bcd::to_decimal(raw);
+    if value < min || value > max {
+        return Err(Error::InvalidBcd { register });
+    }
+
+    Ok(value)
+}
+
+/// Decode a raw hours register value into a 0-23 hour.
+///
+/// Handles both 12-hour and 24-hour modes: 12 AM decodes to 0 and 12 PM to 12.
+/// The BCD digits are not validated.
+pub fn decode_hour(raw_hour: u8) -> u8 {
+    if (raw_hour & 0b0100_0000) != 0 {
+        // 12-hour mode
+        // Extract the Hour part (4-0 bits)
+        let hr = bcd::to_decimal(raw_hour & 0b0001_1111);
+        // Extract the AM/PM (5th bit). if it is set, then it is PM
+        let pm = (raw_hour & 0b0010_0000) != 0;
+
+        // Convert it to 24 hour format:
+        if pm && hr != 12 {
+            hr + 12
+        } else if !pm && hr == 12 {
+            0
+        } else {
+            hr
+        }
+    } else {
+        // 24-hour mode
+        // Extrac the hour value from 5-0 bits
+        bcd::to_decimal(raw_hour & 0b0011_1111)
+    }
+}
+
+/// Decode a raw hours register value into a 0-23 hour, rejecting values that
+/// are not a valid encoding in the selected mode.
+///
+/// In 12-hour mode the hour must be 1-12, so a corrupted register holding
+/// e.g. hour 0 or 13 returns [`Error::InvalidBcd`] instead of a plausible but
+/// wrong time.
+fn decode_hour_checked<E>(raw_hour: u8) -> Result<u8, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    // Handle both 12-hour and 24-hour modes for hours
+    if (raw_hour & HOUR_12_BIT) != 0 {
+        decode_bcd(raw_hour & 0b0001_1111, Register::Hours, 1, 12)?;
+    } else {
+        decode_bcd(raw_hour & 0b0011_1111, Register::Hours, 0, 23)?;
+    }
+
+    Ok(decode_hour(raw_hour))
+}
+
+/// Hour format selected by bit 6 of a raw hours register value.
+fn hour_format(raw_hour: u8) -> HourFormat {
+    if (raw_hour & HOUR_12_BIT) != 0 {
+        HourFormat::TwelveHour
+    } else {
+        HourFormat::TwentyFourHour
+    }
+}
+
+/// Decode the seven DS1307 timekeeping registers (0x00-0x06) into a
+/// [`DateTime`](rtc_hal::datetime::DateTime), without any bus access.
+///
+/// Both 12-hour and 24-hour modes are handled. The CH bit and the day of week
+/// register are ignored. Returns [`Error::InvalidBcd`] if a register does not
+/// hold a valid value.
+///
+/// This is the decoding used by [`Rtc::get_datetime`] with the default century
+/// base (2000-2099), so it can be applied to register bytes captured elsewhere,
+/// e.g. with a logic analyzer.
+pub fn decode_datetime<E>(data: &[u8; 7]) -> Result<rtc_hal::datetime::DateTime, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    decode_registers(data, DEFAULT_CENTURY_BASE)
+}
+
+/// Decode the seven DS1307 timekeeping registers, mapping the two-digit year
+/// onto `century_base..=century_base + 99`.
+fn decode_registers<E>(
+    data: &[u8; 7],
+    century_base: u16,
+) -> Result<rtc_hal::datetime::DateTime, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    // Convert from BCD format and extract fields, rejecting corrupted values
+    let second = decode_bcd(data[0] & 0b0111_1111, Register::Seconds, 0, 59)?; // mask CH (clock halt) bit
+    let minute = decode_bcd(data[1], Register::Minutes, 0, 59)?;
+
+    let hour = decode_hour_checked(data[2])?;
+
+    let day_of_month = decode_bcd(data[4], Register::Date, 1, 31)?;
+    let month = decode_bcd(data[5], Register::Month, 1, 12)?;
+    let year = century_base + decode_bcd(data[6], Register::Year, 0, 99)? as u16;
+
+    rtc_hal::datetime::DateTime::new(year, month, day_of_month, hour, minute, second)
+        .map_err(crate::error::Error::DateTime)
+}
+
+/// Encode a 0-23 hour into a raw hours register value in 24-hour mode.
+///
+/// Bit 6 (12/24-hour mode select) is left cleared. `hour` is not validated.
+pub fn encode_hour_24(hour: u8) -> u8 {
+    bcd::from_decimal(hour) & 0b0011_1111
+}
+
+/// Encode a 0-23 hour into a raw hours register value in 12-hour mode.
+///
+/// Sets bit 6 (12-hour mode) and the AM/PM bit: hour 0 becomes 12 AM and
+/// hour 12 becomes 12 PM. `hour` is not validated.
+pub fn encode_hour_12(hour: u8) -> u8 {
+    // Convert 0-23 to 1-12 with AM/PM
+    let pm = hour >= 12;
+    let hr = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+
+    let mut value = HOUR_12_BIT | bcd::from_decimal(hr);
+    if pm {
+        value |= PM_BIT;
+    }
+    value
+}
+
+/// Encode a 0-23 hour into a raw hours register value in the given format.
+fn encode_hour(hour: u8, format: HourFormat) -> u8 {
+    match format {
+        HourFormat::TwentyFourHour => encode_hour_24(hour),
+        HourFormat::TwelveHour => encode_hour_12(hour),
+    }
+}
+
+/// Day of week register value (1-7) for `weekday`, counting `weekday_epoch` as 1.
+fn weekday_to_register(weekday: Weekday, weekday_epoch: Weekday) -> u8 {
+    (weekday.to_number() + 7 - weekday_epoch.to_number()) % 7 + 1
+}
+
+/// Weekday for a day of week register value (1-7), counting `weekday_epoch` as 1.
+fn weekday_from_register(value: u8, weekday_epoch: Weekday) -> Result<Weekday, DateTimeError> {
+    if !(1..=7).contains(&value) {
+        return Err(DateTimeError::InvalidWeekday);
+    }
+
+    Weekday::from_number((value - 1 + weekday_epoch.to_number() - 1) % 7 + 1)
+}
+
+/// Encode a [`DateTime`](rtc_hal::datetime::DateTime) into the seven DS1307
+/// timekeeping registers (0x00-0x06).
+///
+/// The CH bit in the seconds register is left cleared. The year is validated
+/// before anything is encoded, so callers can rely on an error being returned
+/// before any bus access.
+pub(crate) fn encode_registers(
+    datetime: &rtc_hal::datetime::DateTime,
+    format: HourFormat,
+    weekday_epoch: Weekday,
+    century_base: u16,
+) -> Result<[u8; 7], DateTimeError> {
+    validate_year(datetime.year(), century_base)?;
+
+    let mut data = [0u8; 7];
+
+    // Seconds register (0x00)
+    // For normal operation, CH bit should be 0 (clock enabled)
+    data[0] = bcd::from_decimal(datetime.second()) & 0b0111_1111; // Clear CH bit
+
+    // Minutes register (0x01)
+    data[1] = bcd::from_decimal(datetime.minute());
+
+    // Hours register (0x02)
+    data[2] = encode_hour(datetime.hour(), format);
+
+    let weekday = datetime.calculate_weekday()?;
+
+    // Day of week register (0x03) - 1=`weekday_epoch`
+    data[3] = bcd::from_decimal(weekday_to_register(weekday, weekday_epoch));
+
+    // Day of month register (0x04)
+    data[4] = bcd::from_decimal(datetime.day_of_month());
+
+    // Month register (0x05)
+    data[5] = bcd::from_decimal(datetime.month());
+
+    // Year register (0x06) - only last 2 digits (00-99)
+    let year_2digit = (datetime.year() - century_base) as u8;
+    data[6] = bcd::from_decimal(year_2digit);
+
+    Ok(data)
+}
+
+/// Encode a [`DateTime`](rtc_hal::datetime::DateTime) into the seven DS1307
+/// timekeeping registers (0x00-0x06), without any bus access.
+///
+/// The hours register uses 24-hour mode, the day of week register is derived
+/// from the date (1 = Sunday), and the CH bit is left cleared. Returns [`Error::DateTime`]
+/// with [`DateTimeError::InvalidYear`] if the year is outside 2000-2099.
+///
+/// This is the encoding used by [`Rtc::set_datetime`] with the default weekday
+/// numbering and century base.
+pub fn encode_datetime<E>(datetime: &rtc_hal::datetime::DateTime) -> Result<[u8; 7], Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    encode_registers(
+        datetime,
+        HourFormat::TwentyFourHour,
+        Weekday::Sunday,
+        DEFAULT_CENTURY_BASE,
+    )
+    .map_err(Error::DateTime)
+}
+
+/// Build a [`DateTime`](rtc_hal::datetime::DateTime) from components that may
+/// overflow by one carry, e.g. a computed `23:59:60`.
+///
+/// Normalizes exactly these values, carrying into the next larger unit:
+///
+/// - `second == 60` rolls over into the next minute,
+/// - `minute == 60` rolls over into the next hour,
+/// - `hour == 24` rolls over into the next day,
+///
+/// with any resulting carry through day, month and year (including leap
+/// days), so `2024-12-31 23:59:60` becomes `2025-01-01 00:00:00`. Everything
+/// else is rejected with [`Error::DateTime`]: seconds or minutes above 60, hours
+/// above 24, and a date that is invalid on its own (month outside 1-12, day 0
+/// or past the end of the month, e.g. February 29 in a non-leap year) return
+/// the matching [`DateTimeError`], and a result outside 2000-2099 returns
+/// [`DateTimeError::InvalidYear`].
+pub fn normalize_datetime<E>(
+    year: u16,
+    month: u8,
+    day_of_month: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+) -> Result<rtc_hal::datetime::DateTime, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    if hour > 24 {
+        return Err(Error::DateTime(DateTimeError::InvalidHour));
+    }
+    if minute > 60 {
+        return Err(Error::DateTime(DateTimeError::InvalidMinute));
+    }
+    if second > 60 {
+        return Err(Error::DateTime(DateTimeError::InvalidSecond));
+    }
+
+    validate_year(year, DEFAULT_CENTURY_BASE).map_err(Error::DateTime)?;
+    let date = rtc_hal::datetime::DateTime::new(year, month, day_of_month, 0, 0, 0)
+        .map_err(Error::DateTime)?;
+
+    let timestamp =
+        datetime_to_unix(&date) + hour as i64 * 3600 + minute as i64 * 60 + second as i64;
+    unix_to_datetime(timestamp, DEFAULT_CENTURY_BASE).map_err(Error::DateTime)
+}
+
+/// Like [`encode_datetime`], but accepting components that overflow by one
+/// carry; see [`normalize_datetime`] for which inputs are normalized.
+pub fn encode_datetime_normalized<E>(
+    year: u16,
+    month: u8,
+    day_of_month: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+) -> Result<[u8; 7], Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    let datetime = normalize_datetime(year, month, day_of_month, hour, minute, second)?;
+    encode_datetime(&datetime)
+}
+
+/// Compare two sets of timekeeping register values (0x00-0x06) by the time
+/// they represent.
+///
+/// The CH bit in the seconds register is ignored, and the hours registers are
+/// compared as a 0-23 hour, so the same time in 12-hour and 24-hour mode is
+/// considered equivalent. All other registers are compared as-is.
+pub fn registers_equivalent(a: &[u8; 7], b: &[u8; 7]) -> bool {
+    (a[0] & !CH_BIT) == (b[0] & !CH_BIT)
+        && a[1] == b[1]
+        && decode_hour(a[2]) == decode_hour(b[2])
+        && a[3..] == b[3..]
+}
 
 impl<I2C> Rtc for Ds1307<I2C>
 where
@@ -13,269 +550,2524 @@ where
 {
     /// Read the current date and time from the DS1307.
     fn get_datetime(&mut self) -> Result<rtc_hal::datetime::DateTime, Self::Error> {
+        let data = self.read_raw_datetime()?;
+
+        self.decode_for_device(&data)
+    }
+
+    /// Set the current date and time in the DS1307.
+    fn set_datetime(&mut self, datetime: &rtc_hal::datetime::DateTime) -> Result<(), Self::Error> {
+        self.set_datetime_with_format(datetime, HourFormat::TwentyFourHour)
+    }
+}
+
+impl<I2C, E> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Check a raw hours register value against the
+    /// [`assumed_hour_format`](Self::assumed_hour_format), if any.
+    fn check_hour_format(&self, raw_hour: u8) -> Result<(), Error<E>> {
+        match self.assumed_hour_format() {
+            Some(format) if hour_format(raw_hour) != format => Err(Error::HourFormatMismatch),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record that the driver just wrote the hours register in `format`, so an
+    /// [`assumed_hour_format`](Self::assumed_hour_format), if any, follows it.
+    pub(crate) fn follow_hour_format(&mut self, format: HourFormat) {
+        if self.assumed_hour_format().is_some() {
+            self.assume_hour_format(Some(format));
+        }
+    }
+
+    /// Decode timekeeping registers read from the device, honoring this
+    /// driver's century base and assumed hour format.
+    fn decode_for_device(&self, data: &[u8; 7]) -> Result<rtc_hal::datetime::DateTime, Error<E>> {
+        self.check_hour_format(data[2])?;
+        decode_registers(data, self.century_base())
+    }
+
+    /// Check that `datetime` can be stored with this driver's century base,
+    /// without any bus access.
+    ///
+    /// Like [`validate_for_ds1307`], but honoring
+    /// [`set_century_base`](Self::set_century_base).
+    pub fn validate_datetime(
+        &self,
+        datetime: &rtc_hal::datetime::DateTime,
+    ) -> Result<(), Error<E>> {
+        validate_year(datetime.year(), self.century_base()).map_err(Error::DateTime)
+    }
+
+    /// Encode `datetime` using this driver's weekday numbering and century base.
+    pub(crate) fn encode_for_device(
+        &self,
+        datetime: &rtc_hal::datetime::DateTime,
+        format: HourFormat,
+    ) -> Result<[u8; 7], Error<E>> {
+        encode_registers(datetime, format, self.weekday_epoch(), self.century_base())
+            .map_err(Error::DateTime)
+    }
+
+    /// Read the current date and time together with the raw register snapshot it was decoded from.
+    ///
+    /// Bus errors are returned in the outer `Result`. Otherwise the snapshot is
+    /// always returned, alongside the decoding result, so a failure such as
+    /// [`Error::InvalidBcd`] can be logged with the exact bytes that caused it.
+    #[allow(clippy::type_complexity)]
+    pub fn get_datetime_with_raw(
+        &mut self,
+    ) -> Result<(RawDateTime, Result<rtc_hal::datetime::DateTime, Error<E>>), Error<E>> {
+        let raw = RawDateTime(self.read_raw_datetime()?);
+        let datetime = self.decode_for_device(&raw.0);
+
+        Ok((raw, datetime))
+    }
+
+    /// Read the seven timekeeping registers (0x00-0x06) without decoding them.
+    ///
+    /// Performs the same single burst read as [`Rtc::get_datetime`], but returns
+    /// the untouched register bytes, including the CH bit, the 12/24-hour mode
+    /// bit, and any invalid BCD values. Useful for diagnosing unexpected times.
+    pub fn read_raw_datetime(&mut self) -> Result<[u8; 7], Error<E>> {
         // Since DS1307 allows Subsequent registers can be accessed sequentially until a STOP condition is executed
         // Read all 7 registers in one burst operation
         let mut data = [0; 7];
         self.read_register_bytes(Register::Seconds, &mut data)?;
 
-        // Convert from BCD format and extract fields
-        let second = bcd::to_decimal(data[0] & 0b0111_1111); // mask CH (clock halt) bit
-        let minute = bcd::to_decimal(data[1]);
-
-        // Handle both 12-hour and 24-hour modes for hours
-        let raw_hour = data[2];
-        let hour = if (raw_hour & 0b0100_0000) != 0 {
-            // 12-hour mode
-            // Extract the Hour part (4-0 bits)
-            let hr = bcd::to_decimal(raw_hour & 0b0001_1111);
-            // Extract the AM/PM (5th bit). if it is set, then it is PM
-            let pm = (raw_hour & 0b0010_0000) != 0;
-
-            // Convert it to 24 hour format:
-            if pm && hr != 12 {
-                hr + 12
-            } else if !pm && hr == 12 {
-                0
-            } else {
-                hr
-            }
-        } else {
-            // 24-hour mode
-            // Extrac the hour value from 5-0 bits
-            bcd::to_decimal(raw_hour & 0b0011_1111)
-        };
+        Ok(data)
+    }
+
+    /// Read the current date and time, failing if the oscillator is halted.
+    ///
+    /// A DS1307 that lost power (or was never initialized) comes up with the
+    /// Clock Halt (CH) bit set and arbitrary register contents. This method
+    /// reads the seconds register first and returns [`Error::ClockHalted`] in
+    /// that case, instead of decoding a potentially misleading time.
+    ///
+    /// If the clock is running, this behaves exactly like [`Rtc::get_datetime`].
+    pub fn get_datetime_checked(&mut self) -> Result<rtc_hal::datetime::DateTime, Error<E>> {
+        let seconds = self.read_register(Register::Seconds)?;
+        if (seconds & CH_BIT) != 0 {
+            return Err(Error::ClockHalted);
+        }
+
+        self.get_datetime()
+    }
+
+    /// Check whether the stored time can be trusted, with a single burst read.
+    ///
+    /// Reads the seven timekeeping registers once and returns the first
+    /// problem found:
+    ///
+    /// - [`Error::ClockHalted`] if the oscillator is halted (CH bit set),
+    /// - [`Error::InvalidBcd`] if any register, including the day of week,
+    ///   does not hold a valid BCD value for its range,
+    /// - [`Error::HourFormatMismatch`] if an
+    ///   [`assumed_hour_format`](Self::assumed_hour_format) is set and the chip
+    ///   runs in the other mode,
+    /// - [`Error::DateTime`] if the decoded date is impossible (e.g. February 30).
+    ///
+    /// A valid two-digit year always decodes into the century window (2000-2099
+    /// by default), so no separate year check is needed. Intended for boot
+    /// time, to decide between trusting the RTC and falling back to another
+    /// time source.
+    pub fn health_check(&mut self) -> Result<(), Error<E>> {
+        let data = self.read_raw_datetime()?;
+
+        if (data[0] & CH_BIT) != 0 {
+            return Err(Error::ClockHalted);
+        }
+        decode_bcd(data[3], Register::Day, 1, 7)?;
+        self.decode_for_device(&data)?;
+
+        Ok(())
+    }
+
+    /// Read the current date and time together with the stored day of week.
+    ///
+    /// [`Rtc::get_datetime`] ignores the day of week register (0x03), since the
+    /// weekday can be derived from the date. This method also decodes that
+    /// register from the same burst read, so callers relying on the chip's own
+    /// day-of-week counter can compare it with
+    /// [`DateTime::calculate_weekday`](rtc_hal::datetime::DateTime::calculate_weekday).
+    pub fn get_datetime_with_weekday(
+        &mut self,
+    ) -> Result<(rtc_hal::datetime::DateTime, Weekday), Error<E>> {
+        let data = self.read_raw_datetime()?;
+
+        let datetime = self.decode_for_device(&data)?;
+        let day = decode_bcd(data[3], Register::Day, 1, 7)?;
+        let weekday = weekday_from_register(day, self.weekday_epoch()).map_err(Error::DateTime)?;
+
+        Ok((datetime, weekday))
+    }
+
+    /// Rewrite the day of week register (0x03) so it matches the stored date.
+    ///
+    /// [`Rtc::get_datetime`] ignores the day of week register, so after manual
+    /// register writes it can disagree with the date. This reads the
+    /// timekeeping registers, recomputes the weekday with
+    /// [`DateTime::calculate_weekday`](rtc_hal::datetime::DateTime::calculate_weekday)
+    /// and writes register 0x03 only if it differs, honoring
+    /// [`weekday_epoch`](Self::weekday_epoch).
+    pub fn sync_weekday(&mut self) -> Result<(), Error<E>> {
+        let data = self.read_raw_datetime()?;
+
+        let datetime = self.decode_for_device(&data)?;
+        let weekday = datetime.calculate_weekday().map_err(Error::DateTime)?;
+        let day = weekday_to_register(weekday, self.weekday_epoch());
+
+        if data[3] != day {
+            self.write_register(Register::Day, day)?;
+        }
+
+        Ok(())
+    }
+
+    /// Set the current date and time, encoding the hours register in the given format.
+    ///
+    /// [`Rtc::set_datetime`] always switches the DS1307 to 24-hour mode. Use this
+    /// method with [`HourFormat::TwelveHour`] to keep the chip in 12-hour mode,
+    /// with the AM/PM bit derived from the hour of `datetime`. If an
+    /// [`assumed_hour_format`](Self::assumed_hour_format) is set, it is updated
+    /// to `format` after the write.
+    pub fn set_datetime_with_format(
+        &mut self,
+        datetime: &rtc_hal::datetime::DateTime,
+        format: HourFormat,
+    ) -> Result<(), Error<E>> {
+        let registers = self.encode_for_device(datetime, format)?;
+
+        // Prepare data array for burst write (7 registers)
+        let mut data = [0u8; 8];
+        data[0] = Register::Seconds.addr();
+        data[1..].copy_from_slice(&registers);
+
+        // Write all 7 registers in one burst operation
+        self.write_raw_bytes(&data)?;
+        self.follow_hour_format(format);
+
+        Ok(())
+    }
+
+    /// Provision a freshly powered chip: set the time, start the clock and reset the control register.
+    ///
+    /// Registers 0x00-0x07 are written in a single burst: the seven
+    /// timekeeping registers (24-hour mode, CH cleared) followed by the
+    /// power-on default control value `0x00` (square wave disabled, OUT low).
+    /// The oscillator therefore never runs with stale register contents, and no
+    /// other transaction is issued.
+    ///
+    /// Returns [`Error::DateTime`] before any bus access if `datetime` cannot be
+    /// stored on the DS1307.
+    pub fn initialize(&mut self, datetime: &rtc_hal::datetime::DateTime) -> Result<(), Error<E>> {
+        let registers = self.encode_for_device(datetime, HourFormat::TwentyFourHour)?;
+
+        let mut data = [0u8; 9];
+        data[0] = Register::Seconds.addr();
+        data[1..8].copy_from_slice(&registers);
+        data[8] = 0x00; // Control register default
+
+        self.write_raw_bytes(&data)?;
+        self.follow_hour_format(HourFormat::TwentyFourHour);
+
+        Ok(())
+    }
+
+    /// Set the current date and time, skipping the write if the chip already holds it.
+    ///
+    /// Reads the seven timekeeping registers first and compares them with the
+    /// encoding [`Rtc::set_datetime`] would write. The seconds register is not
+    /// compared, since it keeps ticking, so a `datetime` within the same minute
+    /// as the stored time counts as unchanged. A halted clock always triggers a
+    /// write, which starts it.
+    ///
+    /// Returns `true` if the burst write was issued.
+    pub fn set_datetime_if_changed(
+        &mut self,
+        datetime: &rtc_hal::datetime::DateTime,
+    ) -> Result<bool, Error<E>> {
+        let registers = self.encode_for_device(datetime, HourFormat::TwentyFourHour)?;
+        let current = self.read_raw_datetime()?;
+
+        let running = (current[0] & CH_BIT) == 0;
+        if running && current[1..] == registers[1..] {
+            return Ok(false);
+        }
+
+        let mut data = [0u8; 8];
+        data[0] = Register::Seconds.addr();
+        data[1..].copy_from_slice(&registers);
+        self.write_raw_bytes(&data)?;
+        self.follow_hour_format(HourFormat::TwentyFourHour);
+
+        Ok(true)
+    }
+
+    /// Set the current date and time without changing whether the clock is running.
+    ///
+    /// [`Rtc::set_datetime`] always clears the Clock Halt (CH) bit, starting the
+    /// oscillator. This method reads the seconds register first and keeps its
+    /// CH bit in the burst write, so a halted clock can be staged with a new
+    /// time and started later (for example with
+    /// [`RtcPowerControl::start_clock`](rtc_hal::control::RtcPowerControl::start_clock)).
+    pub fn set_datetime_keep_halt(
+        &mut self,
+        datetime: &rtc_hal::datetime::DateTime,
+    ) -> Result<(), Error<E>> {
+        let registers = self.encode_for_device(datetime, HourFormat::TwentyFourHour)?;
+        let halted = self.read_register(Register::Seconds)? & CH_BIT;
+
+        let mut data = [0u8; 8];
+        data[0] = Register::Seconds.addr();
+        data[1..].copy_from_slice(&registers);
+        data[1] |= halted;
+
+        self.write_raw_bytes(&data)?;
+        self.follow_hour_format(HourFormat::TwentyFourHour);
+
+        Ok(())
+    }
+
+    /// Set the current date and time, then read it back to confirm the write.
+    ///
+    /// After the burst write, the seven timekeeping registers are read back and
+    /// decoded. The weekday is ignored since it is recomputed on both sides, and
+    /// the read-back time may be up to one second ahead of `datetime` to allow
+    /// for the clock ticking during the round trip. Any other difference returns
+    /// [`Error::VerifyMismatch`].
+    pub fn set_datetime_verified(
+        &mut self,
+        datetime: &rtc_hal::datetime::DateTime,
+    ) -> Result<(), Error<E>> {
+        self.set_datetime(datetime)?;
+        let read_back = self.get_datetime()?;
+
+        let drift = datetime_to_unix(&read_back) - datetime_to_unix(datetime);
+        if !(0..=1).contains(&drift) {
+            return Err(Error::VerifyMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Read the current date and time as seconds since the Unix epoch (UTC).
+    pub fn get_unix_timestamp(&mut self) -> Result<i64, Error<E>> {
+        let datetime = self.get_datetime()?;
+        Ok(datetime_to_unix(&datetime))
+    }
+
+    /// Set the current date and time from seconds since the Unix epoch (UTC).
+    ///
+    /// Returns [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the
+    /// timestamp falls outside the century window (2000-2099 by default, see
+    /// [`set_century_base`](Self::set_century_base)).
+    pub fn set_unix_timestamp(&mut self, timestamp: i64) -> Result<(), Error<E>> {
+        let datetime = unix_to_datetime(timestamp, self.century_base()).map_err(Error::DateTime)?;
+        self.set_datetime(&datetime)
+    }
+
+    /// Shift the stored date and time by a signed number of seconds.
+    ///
+    /// Reads the current time, applies `delta_seconds` with carry across
+    /// minute, hour, day, month and year boundaries (including leap days), and
+    /// writes the result back. The 12/24-hour mode and the Clock Halt (CH) bit
+    /// are kept as they were, so a halted clock stays halted. Returns
+    /// [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the result
+    /// falls outside the century window (2000-2099 by default), in which case
+    /// nothing is written.
+    pub fn adjust_datetime(&mut self, delta_seconds: i64) -> Result<(), Error<E>> {
+        let current = self.read_raw_datetime()?;
+        let adjusted = datetime_to_unix(&self.decode_for_device(&current)?)
+            .checked_add(delta_seconds)
+            .ok_or(Error::DateTime(DateTimeError::InvalidYear))?;
+        let datetime = unix_to_datetime(adjusted, self.century_base()).map_err(Error::DateTime)?;
+        let registers = self.encode_for_device(&datetime, hour_format(current[2]))?;
+
+        let mut data = [0u8; 8];
+        data[0] = Register::Seconds.addr();
+        data[1..].copy_from_slice(&registers);
+        data[1] |= current[0] & CH_BIT;
+
+        self.write_raw_bytes(&data)
+    }
+
+    /// Set only the time of day, in a single burst write of registers 0x00-0x02.
+    ///
+    /// The date registers are left untouched and the CH bit is preserved, so a
+    /// halted clock stays halted. Like [`Rtc::set_datetime`], this switches the
+    /// DS1307 to 24-hour mode.
+    pub fn set_time(&mut self, hour: u8, minute: u8, second: u8) -> Result<(), Error<E>> {
+        if hour > 23 {
+            return Err(Error::DateTime(DateTimeError::InvalidHour));
+        }
+        if minute > 59 {
+            return Err(Error::DateTime(DateTimeError::InvalidMinute));
+        }
+        if second > 59 {
+            return Err(Error::DateTime(DateTimeError::InvalidSecond));
+        }
+
+        let halted = self.read_register(Register::Seconds)? & CH_BIT;
+
+        self.write_raw_bytes(&[
+            Register::Seconds.addr(),
+            halted | bcd::from_decimal(second),
+            bcd::from_decimal(minute),
+            encode_hour_24(hour),
+        ])?;
+        self.follow_hour_format(HourFormat::TwentyFourHour);
+
+        Ok(())
+    }
+
+    /// Set only the date, in a single burst write of registers 0x03-0x06.
+    ///
+    /// The time registers are left untouched. The day of week register is
+    /// rewritten along with the date so it stays consistent, using
+    /// [`weekday_epoch`](Self::weekday_epoch). Returns [`Error::DateTime`] if the
+    /// date is invalid or the year is outside the century window.
+    pub fn set_date(&mut self, year: u16, month: u8, day_of_month: u8) -> Result<(), Error<E>> {
+        let century_base = self.century_base();
+        validate_year(year, century_base).map_err(Error::DateTime)?;
+        let date = rtc_hal::datetime::DateTime::new(year, month, day_of_month, 0, 0, 0)
+            .map_err(Error::DateTime)?;
+        let weekday = date.calculate_weekday().map_err(Error::DateTime)?;
+
+        self.write_raw_bytes(&[
+            Register::Day.addr(),
+            weekday_to_register(weekday, self.weekday_epoch()),
+            bcd::from_decimal(day_of_month),
+            bcd::from_decimal(month),
+            bcd::from_decimal((year - century_base) as u8),
+        ])
+    }
+
+    /// Read only the seconds register (0-59), ignoring the CH bit.
+    pub fn get_seconds(&mut self) -> Result<u8, Error<E>> {
+        let raw = self.read_register(Register::Seconds)?;
+        decode_bcd(raw & !CH_BIT, Register::Seconds, 0, 59)
+    }
+
+    /// Read only the minutes register (0-59).
+    pub fn get_minutes(&mut self) -> Result<u8, Error<E>> {
+        let raw = self.read_register(Register::Minutes)?;
+        decode_bcd(raw, Register::Minutes, 0, 59)
+    }
+
+    /// Read only the hours register, converted to 24-hour format (0-23).
+    ///
+    /// Both 12-hour and 24-hour register modes are handled, as in
+    /// [`Rtc::get_datetime`].
+    pub fn get_hours(&mut self) -> Result<u8, Error<E>> {
+        let raw = self.read_register(Register::Hours)?;
+        self.check_hour_format(raw)?;
+        decode_hour_checked(raw)
+    }
+
+    /// Read only the day of week register, interpreted using the configured
+    /// [`weekday_epoch`](Self::weekday_epoch).
+    pub fn get_weekday(&mut self) -> Result<Weekday, Error<E>> {
+        let raw = self.read_register(Register::Day)?;
+        let day = decode_bcd(raw, Register::Day, 1, 7)?;
+        weekday_from_register(day, self.weekday_epoch()).map_err(Error::DateTime)
+    }
+
+    /// Read only the day of month register (1-31).
+    pub fn get_day_of_month(&mut self) -> Result<u8, Error<E>> {
+        let raw = self.read_register(Register::Date)?;
+        decode_bcd(raw, Register::Date, 1, 31)
+    }
+
+    /// Read only the month register (1-12).
+    pub fn get_month(&mut self) -> Result<u8, Error<E>> {
+        let raw = self.read_register(Register::Month)?;
+        decode_bcd(raw, Register::Month, 1, 12)
+    }
+
+    /// Read only the year register, as a full year within the century window
+    /// (2000-2099 by default).
+    pub fn get_year(&mut self) -> Result<u16, Error<E>> {
+        let raw = self.read_register(Register::Year)?;
+        Ok(self.century_base() + decode_bcd(raw, Register::Year, 0, 99)? as u16)
+    }
+
+    /// Set only the seconds register (0-59).
+    ///
+    /// The current Clock Halt (CH) bit is preserved, so this neither starts
+    /// nor stops the oscillator.
+    pub fn set_seconds(&mut self, second: u8) -> Result<(), Error<E>> {
+        if second > 59 {
+            return Err(Error::DateTime(DateTimeError::InvalidSecond));
+        }
+
+        let current = self.read_register(Register::Seconds)?;
+        self.write_register(
+            Register::Seconds,
+            (current & CH_BIT) | bcd::from_decimal(second),
+        )
+    }
+
+    /// Set only the minutes register (0-59).
+    pub fn set_minutes(&mut self, minute: u8) -> Result<(), Error<E>> {
+        if minute > 59 {
+            return Err(Error::DateTime(DateTimeError::InvalidMinute));
+        }
+
+        self.write_register(Register::Minutes, bcd::from_decimal(minute))
+    }
+
+    /// Set only the hours register (0-23).
+    ///
+    /// Like [`Rtc::set_datetime`], this switches the DS1307 to 24-hour mode.
+    pub fn set_hours(&mut self, hour: u8) -> Result<(), Error<E>> {
+        if hour > 23 {
+            return Err(Error::DateTime(DateTimeError::InvalidHour));
+        }
+
+        self.write_register(Register::Hours, bcd::from_decimal(hour))?;
+        self.follow_hour_format(HourFormat::TwentyFourHour);
+
+        Ok(())
+    }
+
+    /// Read the hour format the DS1307 is currently running in (bit 6 of the hours register).
+    ///
+    /// [`Rtc::get_datetime`] decodes either mode transparently, so this is the
+    /// way to notice that the mode was changed behind the driver's back, e.g.
+    /// by another bus master after [`Rtc::set_datetime`] selected 24-hour mode.
+    pub fn get_hour_format(&mut self) -> Result<HourFormat, Error<E>> {
+        let raw = self.read_register(Register::Hours)?;
+        Ok(hour_format(raw))
+    }
+
+    /// Switch the DS1307 between 12-hour and 24-hour mode without changing the time.
+    ///
+    /// The current hour is read, re-encoded in `format` (including the AM/PM
+    /// bit in 12-hour mode) and written back in a single register write. No
+    /// write is issued if the chip is already in the requested format.
+    pub fn set_hour_format(&mut self, format: HourFormat) -> Result<(), Error<E>> {
+        let raw = self.read_register(Register::Hours)?;
+        if hour_format(raw) != format {
+            self.write_register(Register::Hours, encode_hour(decode_hour(raw), format))?;
+        }
+        self.follow_hour_format(format);
+
+        Ok(())
+    }
+
+    /// Set only the day of month register (1-31).
+    ///
+    /// The day is not checked against the month currently stored on the chip,
+    /// and the day of week register is not updated.
+    pub fn set_day_of_month(&mut self, day_of_month: u8) -> Result<(), Error<E>> {
+        if day_of_month == 0 || day_of_month > 31 {
+            return Err(Error::DateTime(DateTimeError::InvalidDay));
+        }
+
+        self.write_register(Register::Date, bcd::from_decimal(day_of_month))
+    }
+
+    /// Set only the month register (1-12).
+    ///
+    /// The day of week register is not updated.
+    pub fn set_month(&mut self, month: u8) -> Result<(), Error<E>> {
+        if month == 0 || month > 12 {
+            return Err(Error::DateTime(DateTimeError::InvalidMonth));
+        }
+
+        self.write_register(Register::Month, bcd::from_decimal(month))
+    }
+
+    /// Set only the year register, within the century window (2000-2099 by default).
+    ///
+    /// The day of week register is not updated.
+    pub fn set_year(&mut self, year: u16) -> Result<(), Error<E>> {
+        let century_base = self.century_base();
+        validate_year(year, century_base).map_err(Error::DateTime)?;
+
+        self.write_register(
+            Register::Year,
+            bcd::from_decimal((year - century_base) as u8),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+    use rtc_hal::datetime::DateTime;
+
+    fn new_ds1307(i2c: I2cMock) -> Ds1307<I2cMock> {
+        Ds1307::new(i2c)
+    }
+
+    #[test]
+    fn test_get_datetime_24h_mode() {
+        // Simulate reading: sec=0x25(25), min=0x59(59), hour=0x23(23h 24h mode),
+        // day_of_week=0x04, day_of_month=0x15(15), month=0x08(August), year=0x23(2023)
+        let data = [0x25, 0x59, 0x23, 0x04, 0x15, 0x08, 0x23];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let dt = ds1307.get_datetime().unwrap();
+        assert_eq!(dt.second(), 25);
+        assert_eq!(dt.minute(), 59);
+        assert_eq!(dt.hour(), 23);
+
+        assert_eq!(dt.day_of_month(), 15);
+        assert_eq!(dt.month(), 8);
+
+        assert_eq!(dt.year(), 2023);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_within_base_century() {
+        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
+        // base_century = 20, so 2000-2199 valid. 2023 fits.
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x45, // sec
+                0x30, // min
+                0x15, // hour (24h)
+                0x04, // weekday (2025-08-27 is Wednesday)
+                0x27, // day
+                0x8,  // month
+                0x25, // year (25)
+            ],
+        )];
+
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_datetime(&datetime).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_invalid_year() {
+        let datetime = DateTime::new(1980, 1, 1, 0, 0, 0).unwrap();
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+
+        let result = ds1307.set_datetime(&datetime);
+        assert!(matches!(
+            result,
+            Err(crate::error::Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_12h_mode_am() {
+        // 01:15:30 AM, January 1, 2023 (Sunday)
+        let data = [
+            0x30,        // seconds = 30
+            0x15,        // minutes = 15
+            0b0100_0001, // hour register: 12h mode, hr=1, AM
+            0x01,        // weekday = Sunday
+            0x01,        // day of month
+            0x01,        // month = January, century=0
+            0x23,        // year = 23
+        ];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let dt = ds1307.get_datetime().unwrap();
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (1, 15, 30));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_12h_mode_pm() {
+        // 11:45:50 PM, December 31, 2023 (Sunday)
+        let data = [
+            0x50,        // seconds = 50
+            0x45,        // minutes = 45
+            0b0111_0001, // hour register: 12h mode, hr=11, PM
+            0x01,        // weekday = Sunday
+            0x31,        // day of month
+            0x12,        // month = December
+            0x23,        // year = 23
+        ];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let dt = ds1307.get_datetime().unwrap();
+        assert_eq!(dt.hour(), 23); // 11 PM -> 23h
+        assert_eq!(dt.month(), 12);
+        assert_eq!(dt.day_of_month(), 31);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_12h_mode_12am() {
+        // 12:10:00 AM, Feb 1, 2023 (Wednesday)
+        let data = [
+            0x00,        // seconds = 0
+            0x10,        // minutes = 10
+            0b0101_0010, // 12h mode (bit 6=1), hr=12 (0x12), AM (bit5=0)
+            0x03,        // weekday = Tuesday
+            0x01,        // day of month
+            0x02,        // month = Feb
+            0x23,        // year = 23
+        ];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let dt = ds1307.get_datetime().unwrap();
+        assert_eq!(dt.hour(), 0); // 12 AM should be 0h
+        assert_eq!(dt.minute(), 10);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_12h_mode_12pm() {
+        // 12:45:00 PM, Mar 1, 2023 (Wednesday)
+        let data = [
+            0x00,        // seconds = 0
+            0x45,        // minutes = 45
+            0b0111_0010, // 12h mode, hr=12, PM bit set (bit5=1)
+            0x04,        // weekday = Wednesday
+            0x01,        // day of month
+            0x03,        // month = Mar
+            0x23,        // year = 23
+        ];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let dt = ds1307.get_datetime().unwrap();
+        assert_eq!(dt.hour(), 12); // 12 PM should stay 12h
+        assert_eq!(dt.minute(), 45);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_checked_running() {
+        let data = [0x25, 0x59, 0x23, 0x04, 0x15, 0x08, 0x23];
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0x25]),
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], data.to_vec()),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let dt = ds1307.get_datetime_checked().unwrap();
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (23, 59, 25));
+        assert_eq!(dt.year(), 2023);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_checked_halted() {
+        // CH bit set: the remaining registers must not be read
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            vec![CH_BIT | 0x25],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let result = ds1307.get_datetime_checked();
+        assert!(matches!(result, Err(Error::ClockHalted)));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_checked_i2c_error() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let result = ds1307.get_datetime_checked();
+        assert!(matches!(result, Err(Error::I2c(_))));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_with_format_24h() {
+        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x45, // sec
+                0x30, // min
+                0x15, // hour (24h)
+                0x04, // weekday
+                0x27, // day
+                0x08, // month
+                0x25, // year
+            ],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307
+            .set_datetime_with_format(&datetime, HourFormat::TwentyFourHour)
+            .unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_with_format_12h_pm() {
+        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x45,        // sec
+                0x30,        // min
+                0b0110_0011, // hour: 12h mode, PM, 3
+                0x04,        // weekday
+                0x27,        // day
+                0x08,        // month
+                0x25,        // year
+            ],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307
+            .set_datetime_with_format(&datetime, HourFormat::TwelveHour)
+            .unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_with_format_12h_midnight_and_noon() {
+        let midnight = DateTime::new(2025, 8, 27, 0, 5, 0).unwrap();
+        let noon = DateTime::new(2025, 8, 27, 12, 5, 0).unwrap();
+        let expectations = [
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x00,
+                    0x05,
+                    0b0101_0010, // 12h mode, AM, 12
+                    0x04,
+                    0x27,
+                    0x08,
+                    0x25,
+                ],
+            ),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x00,
+                    0x05,
+                    0b0111_0010, // 12h mode, PM, 12
+                    0x04,
+                    0x27,
+                    0x08,
+                    0x25,
+                ],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307
+            .set_datetime_with_format(&midnight, HourFormat::TwelveHour)
+            .unwrap();
+        ds1307
+            .set_datetime_with_format(&noon, HourFormat::TwelveHour)
+            .unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_with_format_12h_round_trip() {
+        let datetime = DateTime::new(2025, 8, 27, 23, 59, 58).unwrap();
+        let expectations = [
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x58,
+                    0x59,
+                    0b0111_0001, // 12h mode, PM, 11
+                    0x04,
+                    0x27,
+                    0x08,
+                    0x25,
+                ],
+            ),
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x58, 0x59, 0b0111_0001, 0x04, 0x27, 0x08, 0x25],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307
+            .set_datetime_with_format(&datetime, HourFormat::TwelveHour)
+            .unwrap();
+        assert_eq!(ds1307.get_datetime().unwrap(), datetime);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_datetime_to_unix() {
+        let cases = [
+            ((2000, 1, 1, 0, 0, 0), 946_684_800),
+            ((2024, 2, 29, 12, 0, 0), 1_709_208_000),
+            ((2025, 8, 27, 15, 30, 45), 1_756_308_645),
+            ((2099, 12, 31, 23, 59, 59), 4_102_444_799),
+        ];
+
+        for ((y, mo, d, h, mi, s), ts) in cases {
+            let datetime = DateTime::new(y, mo, d, h, mi, s).unwrap();
+            assert_eq!(datetime_to_unix(&datetime), ts);
+            assert_eq!(
+                unix_to_datetime(ts, DEFAULT_CENTURY_BASE).unwrap(),
+                datetime
+            );
+        }
+    }
+
+    #[test]
+    fn test_unix_to_datetime_leap_days() {
+        // 2000 is a leap year (divisible by 400)
+        let feb29_2000 = unix_to_datetime(951_782_400, DEFAULT_CENTURY_BASE).unwrap();
+        assert_eq!(feb29_2000, DateTime::new(2000, 2, 29, 0, 0, 0).unwrap());
+
+        // 2023 is not: the day after Feb 28 is Mar 1
+        let mar1_2023 = unix_to_datetime(1_677_628_800, DEFAULT_CENTURY_BASE).unwrap();
+        assert_eq!(mar1_2023, DateTime::new(2023, 3, 1, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_unix_to_datetime_out_of_range() {
+        assert_eq!(
+            unix_to_datetime(946_684_799, DEFAULT_CENTURY_BASE),
+            Err(DateTimeError::InvalidYear)
+        );
+        assert_eq!(
+            unix_to_datetime(4_102_444_800, DEFAULT_CENTURY_BASE),
+            Err(DateTimeError::InvalidYear)
+        );
+        assert_eq!(
+            unix_to_datetime(-1, DEFAULT_CENTURY_BASE),
+            Err(DateTimeError::InvalidYear)
+        );
+    }
+
+    #[test]
+    fn test_get_unix_timestamp() {
+        let data = [0x45, 0x30, 0x15, 0x04, 0x27, 0x08, 0x25];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert_eq!(ds1307.get_unix_timestamp().unwrap(), 1_756_308_645);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_unix_timestamp() {
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x45,
+                0x30,
+                0x15,
+                0x04,
+                0x27,
+                0x08,
+                0x25,
+            ],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_unix_timestamp(1_756_308_645).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_unix_timestamp_out_of_range() {
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+
+        let result = ds1307.set_unix_timestamp(0);
+        assert!(matches!(
+            result,
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_read_raw_datetime() {
+        // CH bit set, 12-hour mode and an invalid BCD month are returned as-is
+        let data = [0x80 | 0x25, 0x59, 0b0110_1011, 0x04, 0x15, 0x1F, 0x23];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert_eq!(ds1307.read_raw_datetime().unwrap(), data);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_read_raw_datetime_i2c_error() {
+        let expectations =
+            [
+                I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0x00; 7])
+                    .with_error(embedded_hal::i2c::ErrorKind::Other),
+            ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert!(matches!(ds1307.read_raw_datetime(), Err(Error::I2c(_))));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_seconds_preserves_ch_bit() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![CH_BIT | 0x12]),
+            I2cTrans::write(0x68, vec![Register::Seconds.addr(), CH_BIT | 0x45]),
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0x12]),
+            I2cTrans::write(0x68, vec![Register::Seconds.addr(), 0x07]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_seconds(45).unwrap();
+        ds1307.set_seconds(7).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_single_fields() {
+        let expectations = [
+            I2cTrans::write(0x68, vec![Register::Minutes.addr(), 0x59]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0x23]),
+            I2cTrans::write(0x68, vec![Register::Date.addr(), 0x31]),
+            I2cTrans::write(0x68, vec![Register::Month.addr(), 0x12]),
+            I2cTrans::write(0x68, vec![Register::Year.addr(), 0x99]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_minutes(59).unwrap();
+        ds1307.set_hours(23).unwrap();
+        ds1307.set_day_of_month(31).unwrap();
+        ds1307.set_month(12).unwrap();
+        ds1307.set_year(2099).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_single_fields_out_of_range() {
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+
+        assert!(matches!(
+            ds1307.set_seconds(60),
+            Err(Error::DateTime(DateTimeError::InvalidSecond))
+        ));
+        assert!(matches!(
+            ds1307.set_minutes(60),
+            Err(Error::DateTime(DateTimeError::InvalidMinute))
+        ));
+        assert!(matches!(
+            ds1307.set_hours(24),
+            Err(Error::DateTime(DateTimeError::InvalidHour))
+        ));
+        assert!(matches!(
+            ds1307.set_day_of_month(0),
+            Err(Error::DateTime(DateTimeError::InvalidDay))
+        ));
+        assert!(matches!(
+            ds1307.set_day_of_month(32),
+            Err(Error::DateTime(DateTimeError::InvalidDay))
+        ));
+        assert!(matches!(
+            ds1307.set_month(13),
+            Err(Error::DateTime(DateTimeError::InvalidMonth))
+        ));
+        assert!(matches!(
+            ds1307.set_year(1999),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+        assert!(matches!(
+            ds1307.set_year(2100),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_single_fields() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![CH_BIT | 0x42]),
+            I2cTrans::write_read(0x68, vec![Register::Minutes.addr()], vec![0x37]),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x21]),
+            I2cTrans::write_read(0x68, vec![Register::Date.addr()], vec![0x29]),
+            I2cTrans::write_read(0x68, vec![Register::Month.addr()], vec![0x02]),
+            I2cTrans::write_read(0x68, vec![Register::Year.addr()], vec![0x24]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert_eq!(ds1307.get_seconds().unwrap(), 42);
+        assert_eq!(ds1307.get_minutes().unwrap(), 37);
+        assert_eq!(ds1307.get_hours().unwrap(), 21);
+        assert_eq!(ds1307.get_day_of_month().unwrap(), 29);
+        assert_eq!(ds1307.get_month().unwrap(), 2);
+        assert_eq!(ds1307.get_year().unwrap(), 2024);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_single_fields_invalid_bcd() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![CH_BIT | 0x60]),
+            I2cTrans::write_read(0x68, vec![Register::Minutes.addr()], vec![0x1F]),
+            I2cTrans::write_read(0x68, vec![Register::Date.addr()], vec![0x00]),
+            I2cTrans::write_read(0x68, vec![Register::Month.addr()], vec![0x13]),
+            I2cTrans::write_read(0x68, vec![Register::Year.addr()], vec![0xFF]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+        // A raw 0xFF would overflow the year if it were decoded unchecked
+        ds1307.set_century_base(u16::MAX - 100).unwrap();
+
+        assert_eq!(
+            ds1307.get_seconds(),
+            Err(Error::InvalidBcd {
+                register: Register::Seconds
+            })
+        );
+        assert_eq!(
+            ds1307.get_minutes(),
+            Err(Error::InvalidBcd {
+                register: Register::Minutes
+            })
+        );
+        assert_eq!(
+            ds1307.get_day_of_month(),
+            Err(Error::InvalidBcd {
+                register: Register::Date
+            })
+        );
+        assert_eq!(
+            ds1307.get_month(),
+            Err(Error::InvalidBcd {
+                register: Register::Month
+            })
+        );
+        assert_eq!(
+            ds1307.get_year(),
+            Err(Error::InvalidBcd {
+                register: Register::Year
+            })
+        );
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_hours_12h_mode() {
+        let expectations = [
+            // 12 AM
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0101_0010]),
+            // 12 PM
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0111_0010]),
+            // 9 PM
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0110_1001]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert_eq!(ds1307.get_hours().unwrap(), 0);
+        assert_eq!(ds1307.get_hours().unwrap(), 12);
+        assert_eq!(ds1307.get_hours().unwrap(), 21);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_decode_bcd() {
+        assert_eq!(decode_bcd::<()>(0x59, Register::Minutes, 0, 59), Ok(59));
+        assert_eq!(decode_bcd::<()>(0x00, Register::Minutes, 0, 59), Ok(0));
+        assert_eq!(
+            decode_bcd::<()>(0x1F, Register::Minutes, 0, 59),
+            Err(Error::InvalidBcd {
+                register: Register::Minutes
+            })
+        );
+        assert_eq!(
+            decode_bcd::<()>(0xA0, Register::Year, 0, 99),
+            Err(Error::InvalidBcd {
+                register: Register::Year
+            })
+        );
+        assert_eq!(
+            decode_bcd::<()>(0x60, Register::Seconds, 0, 59),
+            Err(Error::InvalidBcd {
+                register: Register::Seconds
+            })
+        );
+        assert_eq!(
+            decode_bcd::<()>(0x00, Register::Date, 1, 31),
+            Err(Error::InvalidBcd {
+                register: Register::Date
+            })
+        );
+    }
+
+    #[test]
+    fn test_get_datetime_invalid_bcd() {
+        let cases = [
+            (
+                [0x5A, 0x59, 0x23, 0x04, 0x15, 0x08, 0x23],
+                Register::Seconds,
+            ),
+            (
+                [0x25, 0x1F, 0x23, 0x04, 0x15, 0x08, 0x23],
+                Register::Minutes,
+            ),
+            ([0x25, 0x59, 0x24, 0x04, 0x15, 0x08, 0x23], Register::Hours),
+            ([0x25, 0x59, 0x23, 0x04, 0x32, 0x08, 0x23], Register::Date),
+            ([0x25, 0x59, 0x23, 0x04, 0x15, 0x13, 0x23], Register::Month),
+            ([0x25, 0x59, 0x23, 0x04, 0x15, 0x08, 0x2C], Register::Year),
+        ];
+
+        for (data, register) in cases {
+            let expectations = [I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                data.to_vec(),
+            )];
+            let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+            assert_eq!(
+                ds1307.get_datetime(),
+                Err(Error::InvalidBcd { register }),
+                "register {register:?}"
+            );
+
+            ds1307.release_i2c().done();
+        }
+    }
+
+    #[test]
+    fn test_adjust_datetime_forward_across_leap_day() {
+        // 2024-02-28 23:59:30 + 45s -> 2024-02-29 00:00:15 (Thursday)
+        let expectations = [
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x30, 0x59, 0x23, 0x04, 0x28, 0x02, 0x24],
+            ),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x15,
+                    0x00,
+                    0x00,
+                    0x05,
+                    0x29,
+                    0x02,
+                    0x24,
+                ],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.adjust_datetime(45).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_adjust_datetime_backward_across_year() {
+        // 2025-01-01 00:00:05 - 10s -> 2024-12-31 23:59:55 (Tuesday)
+        let expectations = [
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x05, 0x00, 0x00, 0x04, 0x01, 0x01, 0x25],
+            ),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x55,
+                    0x59,
+                    0x23,
+                    0x03,
+                    0x31,
+                    0x12,
+                    0x24,
+                ],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.adjust_datetime(-10).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_adjust_datetime_keeps_12h_mode() {
+        // 2024-12-31 11:59:50 PM + 15s -> 2025-01-01 12:00:05 AM (Wednesday)
+        let expectations = [
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x50, 0x59, 0b0111_0001, 0x03, 0x31, 0x12, 0x24],
+            ),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x05,
+                    0x00,
+                    0b0101_0010,
+                    0x04,
+                    0x01,
+                    0x01,
+                    0x25,
+                ],
+            ),
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x05, 0x00, 0b0101_0010, 0x04, 0x01, 0x01, 0x25],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+        ds1307.assume_hour_format(Some(HourFormat::TwelveHour));
+
+        ds1307.adjust_datetime(15).unwrap();
+        assert_eq!(
+            ds1307.get_datetime().unwrap(),
+            DateTime::new(2025, 1, 1, 0, 0, 5).unwrap()
+        );
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_adjust_datetime_keeps_clock_halted() {
+        let expectations = [
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![CH_BIT | 0x30, 0x15, 0x10, 0x03, 0x31, 0x12, 0x24],
+            ),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    CH_BIT | 0x20,
+                    0x15,
+                    0x10,
+                    0x03,
+                    0x31,
+                    0x12,
+                    0x24,
+                ],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.adjust_datetime(-10).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_adjust_datetime_out_of_range() {
+        // 2000-01-01 00:00:00 - 1s falls before the DS1307 range: no write
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            vec![0x00, 0x00, 0x00, 0x07, 0x01, 0x01, 0x00],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert!(matches!(
+            ds1307.adjust_datetime(-1),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    fn verified_write(seconds: u8) -> I2cTrans {
+        I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                seconds,
+                0x59,
+                0x23,
+                0x03,
+                0x31,
+                0x12,
+                0x24,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_set_datetime_verified_matches() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let expectations = [
+            verified_write(0x58),
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x58, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_datetime_verified(&dt).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_verified_allows_one_second_tick() {
+        // 23:59:59 on Dec 31 ticks over into the next year during the read-back
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 59).unwrap();
+        let expectations = [
+            verified_write(0x59),
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x00, 0x00, 0x00, 0x04, 0x01, 0x01, 0x25],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_datetime_verified(&dt).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_verified_mismatch() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let expectations = [
+            verified_write(0x58),
+            // Minutes register reads back wrong
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x58, 0x19, 0x23, 0x03, 0x31, 0x12, 0x24],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert!(matches!(
+            ds1307.set_datetime_verified(&dt),
+            Err(Error::VerifyMismatch)
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_verified_rejects_earlier_read_back() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let expectations = [
+            verified_write(0x58),
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x57, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert!(matches!(
+            ds1307.set_datetime_verified(&dt),
+            Err(Error::VerifyMismatch)
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_hour_format() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x23]),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0111_0001]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert_eq!(
+            ds1307.get_hour_format().unwrap(),
+            HourFormat::TwentyFourHour
+        );
+        assert_eq!(ds1307.get_hour_format().unwrap(), HourFormat::TwelveHour);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_hour_format_detects_external_mode_change() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 30).unwrap();
+        let expectations = [
+            verified_write(0x30),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x23]),
+            // Another master switched the chip to 12-hour mode (11 PM)
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0111_0001]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_datetime(&dt).unwrap();
+        assert_eq!(
+            ds1307.get_hour_format().unwrap(),
+            HourFormat::TwentyFourHour
+        );
+        assert_eq!(ds1307.get_hour_format().unwrap(), HourFormat::TwelveHour);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_hour_format_24_to_12() {
+        let expectations = [
+            // 00h -> 12 AM
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x00]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0b0101_0010]),
+            // 12h -> 12 PM
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x12]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0b0111_0010]),
+            // 23h -> 11 PM
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x23]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0b0111_0001]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        for _ in 0..3 {
+            ds1307.set_hour_format(HourFormat::TwelveHour).unwrap();
+        }
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_hour_format_12_to_24() {
+        let expectations = [
+            // 12 AM -> 00h
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0101_0010]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0x00]),
+            // 9 AM -> 09h
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0100_1001]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0x09]),
+            // 10 PM -> 22h
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0111_0000]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0x22]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        for _ in 0..3 {
+            ds1307.set_hour_format(HourFormat::TwentyFourHour).unwrap();
+        }
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_hour_format_already_in_format() {
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Hours.addr()],
+            vec![0x15],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_hour_format(HourFormat::TwentyFourHour).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_with_weekday() {
+        // 2024-12-31 is a Tuesday, but the chip's counter says Friday (6)
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            vec![0x30, 0x15, 0x08, 0x06, 0x31, 0x12, 0x24],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let (dt, weekday) = ds1307.get_datetime_with_weekday().unwrap();
+        assert_eq!(dt, DateTime::new(2024, 12, 31, 8, 15, 30).unwrap());
+        assert_eq!(weekday, Weekday::Friday);
+        assert_eq!(dt.calculate_weekday().unwrap(), Weekday::Tuesday);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_sync_weekday_rewrites_stale_register() {
+        // 2024-12-31 is a Tuesday (3), but the register says Friday (6)
+        let expectations = [
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x30, 0x15, 0x08, 0x06, 0x31, 0x12, 0x24],
+            ),
+            I2cTrans::write(0x68, vec![Register::Day.addr(), 0x03]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.sync_weekday().unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_sync_weekday_already_consistent() {
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            vec![0x30, 0x15, 0x08, 0x03, 0x31, 0x12, 0x24],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.sync_weekday().unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_with_weekday_invalid() {
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            vec![0x30, 0x15, 0x08, 0x00, 0x31, 0x12, 0x24],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert_eq!(
+            ds1307.get_datetime_with_weekday(),
+            Err(Error::InvalidBcd {
+                register: Register::Day
+            })
+        );
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_keep_halt_while_halted() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0x80]),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x58 | CH_BIT,
+                    0x59,
+                    0x23,
+                    0x03,
+                    0x31,
+                    0x12,
+                    0x24,
+                ],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_datetime_keep_halt(&dt).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_keep_halt_while_running() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0x42]),
+            verified_write(0x58),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_datetime_keep_halt(&dt).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_keep_halt_invalid_year() {
+        let dt = DateTime::new(1999, 12, 31, 23, 59, 58).unwrap();
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+
+        assert_eq!(
+            ds1307.set_datetime_keep_halt(&dt),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_invalid_bcd_not_retried() {
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            vec![0x00, 0x00, 0x00, 0x01, 0x01, 0x13, 0x00],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+        ds1307.set_retries(3);
+
+        assert_eq!(
+            ds1307.get_datetime(),
+            Err(Error::InvalidBcd {
+                register: Register::Month
+            })
+        );
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_registers_equivalent() {
+        let base = [0x30, 0x15, 0x14, 0x03, 0x31, 0x12, 0x24];
+        assert!(registers_equivalent(&base, &base));
+
+        // CH bit set
+        let mut halted = base;
+        halted[0] |= CH_BIT;
+        assert!(registers_equivalent(&base, &halted));
+
+        // 14:xx in 12-hour mode is 2 PM
+        let mut twelve_hour = base;
+        twelve_hour[2] = HOUR_12_BIT | PM_BIT | 0x02;
+        assert!(registers_equivalent(&base, &twelve_hour));
+
+        // 2 AM is a different time
+        twelve_hour[2] = HOUR_12_BIT | 0x02;
+        assert!(!registers_equivalent(&base, &twelve_hour));
+
+        let mut other_second = base;
+        other_second[0] = 0x31;
+        assert!(!registers_equivalent(&base, &other_second));
+
+        let mut other_year = base;
+        other_year[6] = 0x25;
+        assert!(!registers_equivalent(&base, &other_year));
+    }
+
+    #[test]
+    fn test_normalize_datetime_carries_into_next_day() {
+        assert_eq!(
+            normalize_datetime::<()>(2024, 12, 31, 23, 59, 60),
+            Ok(DateTime::new(2025, 1, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            normalize_datetime::<()>(2024, 2, 28, 23, 59, 60),
+            Ok(DateTime::new(2024, 2, 29, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            normalize_datetime::<()>(2023, 2, 28, 24, 0, 0),
+            Ok(DateTime::new(2023, 3, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            normalize_datetime::<()>(2024, 6, 15, 10, 60, 5),
+            Ok(DateTime::new(2024, 6, 15, 11, 0, 5).unwrap())
+        );
+        // In-range values pass through unchanged
+        assert_eq!(
+            normalize_datetime::<()>(2024, 6, 15, 10, 20, 30),
+            Ok(DateTime::new(2024, 6, 15, 10, 20, 30).unwrap())
+        );
+
+        assert_eq!(
+            encode_datetime_normalized::<()>(2024, 12, 31, 23, 59, 60),
+            // 2025-01-01 is a Wednesday (4)
+            Ok([0x00, 0x00, 0x00, 0x04, 0x01, 0x01, 0x25])
+        );
+    }
+
+    #[test]
+    fn test_normalize_datetime_rejects_invalid_inputs() {
+        assert_eq!(
+            normalize_datetime::<()>(2024, 1, 1, 0, 0, 61),
+            Err(Error::DateTime(DateTimeError::InvalidSecond))
+        );
+        assert_eq!(
+            normalize_datetime::<()>(2024, 1, 1, 0, 61, 0),
+            Err(Error::DateTime(DateTimeError::InvalidMinute))
+        );
+        assert_eq!(
+            normalize_datetime::<()>(2024, 1, 1, 25, 0, 0),
+            Err(Error::DateTime(DateTimeError::InvalidHour))
+        );
+        assert_eq!(
+            normalize_datetime::<()>(2023, 2, 29, 0, 0, 0),
+            Err(Error::DateTime(DateTimeError::InvalidDay))
+        );
+        assert_eq!(
+            normalize_datetime::<()>(2024, 13, 1, 0, 0, 0),
+            Err(Error::DateTime(DateTimeError::InvalidMonth))
+        );
+        // Carry out of the supported range
+        assert_eq!(
+            normalize_datetime::<()>(2099, 12, 31, 23, 59, 60),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+        assert_eq!(
+            normalize_datetime::<()>(1999, 12, 31, 23, 59, 60),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+    }
+
+    #[test]
+    fn test_encode_datetime() {
+        let dt = DateTime::new(2024, 2, 29, 13, 45, 7).unwrap();
+        assert_eq!(
+            encode_datetime::<()>(&dt).unwrap(),
+            [0x07, 0x45, 0x13, 0x05, 0x29, 0x02, 0x24]
+        );
+
+        let dt = DateTime::new(2100, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            encode_datetime::<()>(&dt),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+    }
+
+    #[test]
+    fn test_decode_datetime() {
+        // CH bit and weekday are ignored, 12-hour mode is decoded
+        let bytes = [0x80 | 0x07, 0x45, 0b0110_0001, 0x01, 0x29, 0x02, 0x24];
+        assert_eq!(
+            decode_datetime::<()>(&bytes).unwrap(),
+            DateTime::new(2024, 2, 29, 13, 45, 7).unwrap()
+        );
+
+        let bytes = [0x07, 0x45, 0x13, 0x05, 0x29, 0x0A, 0x24];
+        assert_eq!(
+            decode_datetime::<()>(&bytes),
+            Err(Error::InvalidBcd {
+                register: Register::Month
+            })
+        );
+    }
+
+    #[test]
+    fn test_datetime_codec_round_trip() {
+        let dt = DateTime::new(2099, 12, 31, 23, 59, 59).unwrap();
+        let bytes = encode_datetime::<()>(&dt).unwrap();
+        assert_eq!(decode_datetime::<()>(&bytes).unwrap(), dt);
+    }
+
+    #[test]
+    fn test_weekday_register_mapping() {
+        for epoch in 1..=7 {
+            let epoch = Weekday::from_number(epoch).unwrap();
+            assert_eq!(weekday_to_register(epoch, epoch), 1);
+            for n in 1..=7 {
+                let weekday = Weekday::from_number(n).unwrap();
+                let value = weekday_to_register(weekday, epoch);
+                assert!((1..=7).contains(&value));
+                assert_eq!(weekday_from_register(value, epoch).unwrap(), weekday);
+            }
+        }
+
+        assert_eq!(weekday_to_register(Weekday::Sunday, Weekday::Monday), 7);
+        assert_eq!(
+            weekday_from_register(0, Weekday::Sunday),
+            Err(DateTimeError::InvalidWeekday)
+        );
+    }
 
-        // let weekday = Weekday::from_number(bcd::to_decimal(data[3]))
-        //     .map_err(crate::error::Error::DateTime)?;
+    #[test]
+    fn test_set_datetime_with_weekday_epoch() {
+        // 2024-12-31 is a Tuesday: 2 when Monday is 1
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x58,
+                0x59,
+                0x23,
+                0x02,
+                0x31,
+                0x12,
+                0x24,
+            ],
+        )];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+        ds1307.set_weekday_epoch(Weekday::Monday);
 
-        let day_of_month = bcd::to_decimal(data[4]);
-        let month = bcd::to_decimal(data[5]);
-        let year = 2000 + bcd::to_decimal(data[6]) as u16;
+        ds1307.set_datetime(&dt).unwrap();
 
-        rtc_hal::datetime::DateTime::new(year, month, day_of_month, hour, minute, second)
-            .map_err(crate::error::Error::DateTime)
+        ds1307.release_i2c().done();
     }
 
-    /// Set the current date and time in the DS1307.
-    fn set_datetime(&mut self, datetime: &rtc_hal::datetime::DateTime) -> Result<(), Self::Error> {
-        if datetime.year() < 2000 || datetime.year() > 2099 {
-            // DS1307 only allow this date range
-            return Err(crate::error::Error::DateTime(DateTimeError::InvalidYear));
-        }
+    #[test]
+    fn test_get_weekday_with_epoch() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Day.addr()], vec![0x01]),
+            I2cTrans::write_read(0x68, vec![Register::Day.addr()], vec![0x01]),
+            I2cTrans::write_read(0x68, vec![Register::Day.addr()], vec![0x07]),
+            I2cTrans::write_read(0x68, vec![Register::Day.addr()], vec![0x08]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        // Prepare data array for burst write (7 registers)
-        let mut data = [0u8; 8];
-        data[0] = Register::Seconds.addr();
+        assert_eq!(ds1307.weekday_epoch(), Weekday::Sunday);
+        assert_eq!(ds1307.get_weekday().unwrap(), Weekday::Sunday);
 
-        // Seconds register (0x00)
-        // For normal operation, CH bit should be 0 (clock enabled)
-        data[1] = bcd::from_decimal(datetime.second()) & 0b0111_1111; // Clear CH bit
+        ds1307.set_weekday_epoch(Weekday::Monday);
+        assert_eq!(ds1307.get_weekday().unwrap(), Weekday::Monday);
+        assert_eq!(ds1307.get_weekday().unwrap(), Weekday::Sunday);
+        assert_eq!(
+            ds1307.get_weekday(),
+            Err(Error::InvalidBcd {
+                register: Register::Day
+            })
+        );
 
-        // Minutes register (0x01)
-        data[2] = bcd::from_decimal(datetime.minute());
+        ds1307.release_i2c().done();
+    }
 
-        // Hours register (0x02) - set to 24-hour mode
-        // Clear bit 6 (12/24 hour mode bit) to enable 24-hour mode
-        data[3] = bcd::from_decimal(datetime.hour()) & 0b0011_1111;
+    #[test]
+    fn test_century_base_read_and_write() {
+        // With a 1980 base, the two-digit year 05 is 1985 and 99 is 2079
+        let expectations = [
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x00, 0x30, 0x12, 0x05, 0x14, 0x06, 0x05],
+            ),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x00,
+                    0x30,
+                    0x12,
+                    0x01,
+                    0x31,
+                    0x12,
+                    0x99,
+                ],
+            ),
+            I2cTrans::write_read(0x68, vec![Register::Year.addr()], vec![0x42]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        let weekday = datetime
-            .calculate_weekday()
-            .map_err(crate::error::Error::DateTime)?;
+        assert_eq!(ds1307.century_base(), DEFAULT_CENTURY_BASE);
+        ds1307.set_century_base(1980).unwrap();
 
-        // Day of week register (0x03) - 1=Sunday, 7=Saturday
-        data[4] = bcd::from_decimal(weekday.to_number());
+        assert_eq!(
+            ds1307.get_datetime().unwrap(),
+            DateTime::new(1985, 6, 14, 12, 30, 0).unwrap()
+        );
+        ds1307
+            .set_datetime(&DateTime::new(2079, 12, 31, 12, 30, 0).unwrap())
+            .unwrap();
+        assert_eq!(ds1307.get_year().unwrap(), 2022);
 
-        // Day of month register (0x04)
-        data[5] = bcd::from_decimal(datetime.day_of_month());
+        ds1307.release_i2c().done();
+    }
 
-        // Month register (0x05)
-        data[6] = bcd::from_decimal(datetime.month());
+    #[test]
+    fn test_century_base_rejects_out_of_window() {
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+        ds1307.set_century_base(1980).unwrap();
 
-        // Year register (0x06) - only last 2 digits (00-99)
-        let year_2digit = (datetime.year() - 2000) as u8;
-        data[7] = bcd::from_decimal(year_2digit);
+        assert_eq!(
+            ds1307.set_datetime(&DateTime::new(2080, 1, 1, 0, 0, 0).unwrap()),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+        assert_eq!(
+            ds1307.set_year(1979),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+        assert_eq!(
+            ds1307.set_unix_timestamp(315_532_799), // 1979-12-31T23:59:59
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
 
-        // Write all 7 registers in one burst operation
-        self.write_raw_bytes(&data)?;
+        // DateTime cannot represent years before 1970
+        assert_eq!(
+            ds1307.set_century_base(1969),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+        assert_eq!(ds1307.century_base(), 1980);
 
-        Ok(())
+        ds1307.release_i2c().done();
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
-    use rtc_hal::datetime::DateTime;
+    #[test]
+    fn test_raw_datetime_flags_and_display() {
+        let raw = RawDateTime([0x80 | 0x58, 0x59, 0b0111_0001, 0x03, 0x31, 0x12, 0x24]);
+        assert!(raw.is_halted());
+        assert_eq!(raw.hour_format(), HourFormat::TwelveHour);
+        assert_eq!(raw.is_pm(), Some(true));
+        assert_eq!(
+            format!("{raw}"),
+            "yy=24 mm=12 dd=31 hh=11 mi=59 ss=58 dow=03 CH=1 12h PM"
+        );
 
-    fn new_ds1307(i2c: I2cMock) -> Ds1307<I2cMock> {
-        Ds1307::new(i2c)
+        // Invalid BCD values stay visible
+        let raw = RawDateTime([0x58, 0x59, 0x23, 0x03, 0x31, 0x1A, 0x24]);
+        assert!(!raw.is_halted());
+        assert_eq!(raw.is_pm(), None);
+        assert_eq!(
+            format!("{raw}"),
+            "yy=24 mm=1a dd=31 hh=23 mi=59 ss=58 dow=03 CH=0 24h"
+        );
+        assert_eq!(
+            raw.decode::<()>(),
+            Err(Error::InvalidBcd {
+                register: Register::Month
+            })
+        );
     }
 
     #[test]
-    fn test_get_datetime_24h_mode() {
-        // Simulate reading: sec=0x25(25), min=0x59(59), hour=0x23(23h 24h mode),
-        // day_of_week=0x04, day_of_month=0x15(15), month=0x08(August), year=0x23(2023)
-        let data = [0x25, 0x59, 0x23, 0x04, 0x15, 0x08, 0x23];
-        let expectations = [I2cTrans::write_read(
-            0x68,
-            vec![Register::Seconds.addr()],
-            data.to_vec(),
-        )];
+    fn test_get_datetime_with_raw() {
+        let bytes = [0x58, 0x59, 0x23, 0x03, 0x31, 0x1A, 0x24];
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], bytes.to_vec()),
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0; 7])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+        ];
         let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        let dt = ds1307.get_datetime().unwrap();
-        assert_eq!(dt.second(), 25);
-        assert_eq!(dt.minute(), 59);
-        assert_eq!(dt.hour(), 23);
-
-        assert_eq!(dt.day_of_month(), 15);
-        assert_eq!(dt.month(), 8);
+        let (raw, datetime) = ds1307.get_datetime_with_raw().unwrap();
+        assert_eq!(raw, RawDateTime(bytes));
+        assert_eq!(
+            datetime,
+            Err(Error::InvalidBcd {
+                register: Register::Month
+            })
+        );
 
-        assert_eq!(dt.year(), 2023);
+        assert!(matches!(ds1307.get_datetime_with_raw(), Err(Error::I2c(_))));
 
         ds1307.release_i2c().done();
     }
 
     #[test]
-    fn test_set_datetime_within_base_century() {
-        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
-        // base_century = 20, so 2000-2199 valid. 2023 fits.
+    fn test_encode_hour_24_boundaries() {
+        assert_eq!(encode_hour_24(0), 0x00);
+        assert_eq!(encode_hour_24(9), 0x09);
+        assert_eq!(encode_hour_24(12), 0x12);
+        assert_eq!(encode_hour_24(23), 0x23);
+    }
+
+    #[test]
+    fn test_encode_hour_12_boundaries() {
+        // Midnight is 12 AM
+        assert_eq!(encode_hour_12(0), 0b0101_0010);
+        assert_eq!(encode_hour_12(1), 0b0100_0001);
+        assert_eq!(encode_hour_12(11), 0b0101_0001);
+        // Noon is 12 PM
+        assert_eq!(encode_hour_12(12), 0b0111_0010);
+        assert_eq!(encode_hour_12(13), 0b0110_0001);
+        assert_eq!(encode_hour_12(23), 0b0111_0001);
+    }
+
+    #[test]
+    fn test_decode_hour_boundaries() {
+        // 24-hour mode
+        assert_eq!(decode_hour(0x00), 0);
+        assert_eq!(decode_hour(0x12), 12);
+        assert_eq!(decode_hour(0x23), 23);
+
+        // 12-hour mode
+        assert_eq!(decode_hour(0b0101_0010), 0); // 12 AM
+        assert_eq!(decode_hour(0b0100_0001), 1); // 1 AM
+        assert_eq!(decode_hour(0b0101_0001), 11); // 11 AM
+        assert_eq!(decode_hour(0b0111_0010), 12); // 12 PM
+        assert_eq!(decode_hour(0b0110_0001), 13); // 1 PM
+        assert_eq!(decode_hour(0b0111_0001), 23); // 11 PM
+    }
+
+    #[test]
+    fn test_hour_codec_round_trip() {
+        for hour in 0..24 {
+            assert_eq!(decode_hour(encode_hour_24(hour)), hour);
+            assert_eq!(decode_hour(encode_hour_12(hour)), hour);
+        }
+    }
+
+    #[test]
+    fn test_initialize_single_burst() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
         let expectations = [I2cTrans::write(
             0x68,
             vec![
                 Register::Seconds.addr(),
-                0x45, // sec
-                0x30, // min
-                0x15, // hour (24h)
-                0x04, // weekday (2025-08-27 is Wednesday)
-                0x27, // day
-                0x8,  // month
-                0x25, // year (25)
+                0x58,
+                0x59,
+                0x23,
+                0x03,
+                0x31,
+                0x12,
+                0x24,
+                0x00,
             ],
         )];
-
         let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        ds1307.set_datetime(&datetime).unwrap();
+        ds1307.initialize(&dt).unwrap();
 
         ds1307.release_i2c().done();
     }
 
     #[test]
-    fn test_set_datetime_invalid_year() {
-        let datetime = DateTime::new(1980, 1, 1, 0, 0, 0).unwrap();
+    fn test_initialize_invalid_year() {
+        let dt = DateTime::new(2100, 1, 1, 0, 0, 0).unwrap();
         let mut ds1307 = new_ds1307(I2cMock::new(&[]));
 
-        let result = ds1307.set_datetime(&datetime);
-        assert!(matches!(
-            result,
-            Err(crate::error::Error::DateTime(DateTimeError::InvalidYear))
-        ));
+        assert_eq!(
+            ds1307.initialize(&dt),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
 
         ds1307.release_i2c().done();
     }
 
     #[test]
-    fn test_get_datetime_12h_mode_am() {
-        // 01:15:30 AM, January 1, 2023 (Sunday)
-        let data = [
-            0x30,        // seconds = 30
-            0x15,        // minutes = 15
-            0b0100_0001, // hour register: 12h mode, hr=1, AM
-            0x01,        // weekday = Sunday
-            0x01,        // day of month
-            0x01,        // month = January, century=0
-            0x23,        // year = 23
-        ];
+    fn test_set_datetime_if_changed_skips_same_minute() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 0).unwrap();
         let expectations = [I2cTrans::write_read(
             0x68,
             vec![Register::Seconds.addr()],
-            data.to_vec(),
+            vec![0x42, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24],
         )];
         let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        let dt = ds1307.get_datetime().unwrap();
-        assert_eq!((dt.hour(), dt.minute(), dt.second()), (1, 15, 30));
+        assert!(!ds1307.set_datetime_if_changed(&dt).unwrap());
 
         ds1307.release_i2c().done();
     }
 
     #[test]
-    fn test_get_datetime_12h_mode_pm() {
-        // 11:45:50 PM, December 31, 2023 (Sunday)
-        let data = [
-            0x50,        // seconds = 50
-            0x45,        // minutes = 45
-            0b0110_1011, // hour register: 12h mode, hr=11, PM
-            0x01,        // weekday = Sunday
-            0x31,        // day of month
-            0x12,        // month = December
-            0x23,        // year = 23
+    fn test_set_datetime_if_changed_writes_on_difference() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let stale = [
+            // Different minute
+            [0x58, 0x58, 0x23, 0x03, 0x31, 0x12, 0x24],
+            // Clock halted
+            [0x80 | 0x58, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24],
+            // 12-hour mode, 11 PM
+            [0x58, 0x59, 0b0111_0001, 0x03, 0x31, 0x12, 0x24],
         ];
-        let expectations = [I2cTrans::write_read(
+        let mut expectations = vec![];
+        for registers in stale {
+            expectations.push(I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                registers.to_vec(),
+            ));
+            expectations.push(verified_write(0x58));
+        }
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        for _ in 0..3 {
+            assert!(ds1307.set_datetime_if_changed(&dt).unwrap());
+        }
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_validate_for_ds1307() {
+        assert_eq!(DS1307_YEAR_RANGE, 2000..=2099);
+
+        for year in [2000, 2024, 2099] {
+            let dt = DateTime::new(year, 1, 1, 0, 0, 0).unwrap();
+            assert!(validate_for_ds1307::<()>(&dt).is_ok());
+        }
+        for year in [1999, 2100] {
+            let dt = DateTime::new(year, 1, 1, 0, 0, 0).unwrap();
+            assert_eq!(
+                validate_for_ds1307::<()>(&dt),
+                Err(Error::DateTime(DateTimeError::InvalidYear))
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_datetime_with_century_base() {
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+        let dt_1999 = DateTime::new(1999, 1, 1, 0, 0, 0).unwrap();
+        let dt_2099 = DateTime::new(2099, 1, 1, 0, 0, 0).unwrap();
+
+        assert!(ds1307.validate_datetime(&dt_1999).is_err());
+        assert!(ds1307.validate_datetime(&dt_2099).is_ok());
+
+        ds1307.set_century_base(1980).unwrap();
+        assert!(ds1307.validate_datetime(&dt_1999).is_ok());
+        assert!(ds1307.validate_datetime(&dt_2099).is_err());
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_rejects_invalid_12_hour_values() {
+        // 12-hour mode bit set with hour 0, AM and PM, and with hour 13
+        for raw_hour in [0x40, 0x60, 0x53] {
+            let expectations = [I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x30, 0x15, raw_hour, 0x03, 0x31, 0x12, 0x24],
+            )];
+            let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+            assert_eq!(
+                ds1307.get_datetime(),
+                Err(Error::InvalidBcd {
+                    register: Register::Hours
+                })
+            );
+
+            ds1307.release_i2c().done();
+        }
+    }
+
+    #[test]
+    fn test_get_hours_rejects_invalid_12_hour_values() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x40]),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x53]),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x72]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        let invalid = Err(Error::InvalidBcd {
+            register: Register::Hours,
+        });
+        assert_eq!(ds1307.get_hours(), invalid);
+        assert_eq!(ds1307.get_hours(), invalid);
+        // 12 PM is valid
+        assert_eq!(ds1307.get_hours(), Ok(12));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_time_preserves_clock_halt() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], vec![0x80 | 0x12]),
+            I2cTrans::write(
+                0x68,
+                vec![Register::Seconds.addr(), 0x80 | 0x05, 0x30, 0x21],
+            ),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        ds1307.set_time(21, 30, 5).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_time_invalid() {
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+
+        assert_eq!(
+            ds1307.set_time(24, 0, 0),
+            Err(Error::DateTime(DateTimeError::InvalidHour))
+        );
+        assert_eq!(
+            ds1307.set_time(0, 60, 0),
+            Err(Error::DateTime(DateTimeError::InvalidMinute))
+        );
+        assert_eq!(
+            ds1307.set_time(0, 0, 60),
+            Err(Error::DateTime(DateTimeError::InvalidSecond))
+        );
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_date() {
+        // 2025-03-01 is a Saturday (7)
+        let expectations = [I2cTrans::write(
             0x68,
-            vec![Register::Seconds.addr()],
-            data.to_vec(),
+            vec![Register::Day.addr(), 0x07, 0x01, 0x03, 0x25],
         )];
         let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        let dt = ds1307.get_datetime().unwrap();
-        assert_eq!(dt.hour(), 23); // 11 PM -> 23h
-        assert_eq!(dt.month(), 12);
-        assert_eq!(dt.day_of_month(), 31);
+        ds1307.set_date(2025, 3, 1).unwrap();
 
         ds1307.release_i2c().done();
     }
 
     #[test]
-    fn test_get_datetime_12h_mode_12am() {
-        // 12:10:00 AM, Feb 1, 2023 (Wednesday)
-        let data = [
-            0x00,        // seconds = 0
-            0x10,        // minutes = 10
-            0b0101_0010, // 12h mode (bit 6=1), hr=12 (0x12), AM (bit5=0)
-            0x03,        // weekday = Tuesday
-            0x01,        // day of month
-            0x02,        // month = Feb
-            0x23,        // year = 23
+    fn test_set_date_invalid() {
+        let mut ds1307 = new_ds1307(I2cMock::new(&[]));
+
+        assert_eq!(
+            ds1307.set_date(2025, 2, 29),
+            Err(Error::DateTime(DateTimeError::InvalidDay))
+        );
+        assert_eq!(
+            ds1307.set_date(2023, 2, 29),
+            Err(Error::DateTime(DateTimeError::InvalidDay))
+        );
+        assert_eq!(
+            ds1307.set_date(2025, 1, 0),
+            Err(Error::DateTime(DateTimeError::InvalidDay))
+        );
+        assert_eq!(
+            ds1307.set_date(2025, 13, 1),
+            Err(Error::DateTime(DateTimeError::InvalidMonth))
+        );
+        assert_eq!(
+            ds1307.set_date(2100, 1, 1),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        );
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_assume_hour_format_detects_mismatch() {
+        let expectations = [
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x30, 0x15, 0x23, 0x03, 0x31, 0x12, 0x24],
+            ),
+            // Switched to 12-hour mode (11 PM) behind the driver's back
+            I2cTrans::write_read(
+                0x68,
+                vec![Register::Seconds.addr()],
+                vec![0x30, 0x15, 0b0111_0001, 0x03, 0x31, 0x12, 0x24],
+            ),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0111_0001]),
         ];
-        let expectations = [I2cTrans::write_read(
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+        assert_eq!(ds1307.assumed_hour_format(), None);
+        ds1307.assume_hour_format(Some(HourFormat::TwentyFourHour));
+
+        assert_eq!(
+            ds1307.get_datetime().unwrap(),
+            DateTime::new(2024, 12, 31, 23, 15, 30).unwrap()
+        );
+        assert_eq!(ds1307.get_datetime(), Err(Error::HourFormatMismatch));
+        assert_eq!(ds1307.get_hours(), Err(Error::HourFormatMismatch));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_hour_format_updates_assumption() {
+        let expectations = [
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x23]),
+            I2cTrans::write(0x68, vec![Register::Hours.addr(), 0b0111_0001]),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0111_0001]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+        ds1307.assume_hour_format(Some(HourFormat::TwentyFourHour));
+
+        ds1307.set_hour_format(HourFormat::TwelveHour).unwrap();
+        assert_eq!(ds1307.assumed_hour_format(), Some(HourFormat::TwelveHour));
+        assert_eq!(ds1307.get_hours(), Ok(23));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_updates_assumption() {
+        // 2024-12-31 11:15:30 PM, written in 12-hour then 24-hour mode
+        let dt = DateTime::new(2024, 12, 31, 23, 15, 30).unwrap();
+        let expectations = [
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x30,
+                    0x15,
+                    0b0111_0001,
+                    0x03,
+                    0x31,
+                    0x12,
+                    0x24,
+                ],
+            ),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0b0111_0001]),
+            I2cTrans::write(
+                0x68,
+                vec![
+                    Register::Seconds.addr(),
+                    0x30,
+                    0x15,
+                    0x23,
+                    0x03,
+                    0x31,
+                    0x12,
+                    0x24,
+                ],
+            ),
+            I2cTrans::write_read(0x68, vec![Register::Hours.addr()], vec![0x23]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+        ds1307.assume_hour_format(Some(HourFormat::TwentyFourHour));
+
+        ds1307
+            .set_datetime_with_format(&dt, HourFormat::TwelveHour)
+            .unwrap();
+        assert_eq!(ds1307.assumed_hour_format(), Some(HourFormat::TwelveHour));
+        assert_eq!(ds1307.get_hours(), Ok(23));
+
+        ds1307.set_datetime(&dt).unwrap();
+        assert_eq!(
+            ds1307.assumed_hour_format(),
+            Some(HourFormat::TwentyFourHour)
+        );
+        assert_eq!(ds1307.get_hours(), Ok(23));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_datetime_without_assumption() {
+        let dt = DateTime::new(2024, 12, 31, 23, 15, 30).unwrap();
+        let expectations = [I2cTrans::write(
             0x68,
-            vec![Register::Seconds.addr()],
-            data.to_vec(),
+            vec![
+                Register::Seconds.addr(),
+                0x30,
+                0x15,
+                0b0111_0001,
+                0x03,
+                0x31,
+                0x12,
+                0x24,
+            ],
         )];
         let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        let dt = ds1307.get_datetime().unwrap();
-        assert_eq!(dt.hour(), 0); // 12 AM should be 0h
-        assert_eq!(dt.minute(), 10);
+        ds1307
+            .set_datetime_with_format(&dt, HourFormat::TwelveHour)
+            .unwrap();
+        assert_eq!(ds1307.assumed_hour_format(), None);
 
         ds1307.release_i2c().done();
     }
 
     #[test]
-    fn test_get_datetime_12h_mode_12pm() {
-        // 12:45:00 PM, Mar 1, 2023 (Wednesday)
-        let data = [
-            0x00,        // seconds = 0
-            0x45,        // minutes = 45
-            0b0111_0010, // 12h mode, hr=12, PM bit set (bit5=1)
-            0x04,        // weekday = Wednesday
-            0x01,        // day of month
-            0x03,        // month = Mar
-            0x23,        // year = 23
-        ];
-        let expectations = [I2cTrans::write_read(
+    fn test_set_datetime_leap_day() {
+        // 2024-02-29 is a Thursday (5)
+        let dt = DateTime::new(2024, 2, 29, 12, 0, 0).unwrap();
+        let expectations = [I2cTrans::write(
             0x68,
-            vec![Register::Seconds.addr()],
-            data.to_vec(),
+            vec![
+                Register::Seconds.addr(),
+                0x00,
+                0x00,
+                0x12,
+                0x05,
+                0x29,
+                0x02,
+                0x24,
+            ],
         )];
         let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
 
-        let dt = ds1307.get_datetime().unwrap();
-        assert_eq!(dt.hour(), 12); // 12 PM should stay 12h
-        assert_eq!(dt.minute(), 45);
+        ds1307.set_datetime(&dt).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_health_check() {
+        let read = |data: [u8; 7]| {
+            I2cTrans::write_read(0x68, vec![Register::Seconds.addr()], data.to_vec())
+        };
+        let expectations = [
+            read([0x30, 0x15, 0x10, 0x03, 0x31, 0x12, 0x24]),
+            read([0xB0, 0x15, 0x10, 0x03, 0x31, 0x12, 0x24]),
+            read([0x30, 0x15, 0x10, 0x08, 0x31, 0x12, 0x24]),
+            read([0x30, 0x15, 0x10, 0x03, 0x31, 0x1A, 0x24]),
+            // February 30
+            read([0x30, 0x15, 0x10, 0x03, 0x30, 0x02, 0x24]),
+        ];
+        let mut ds1307 = new_ds1307(I2cMock::new(&expectations));
+
+        assert_eq!(ds1307.health_check(), Ok(()));
+        assert_eq!(ds1307.health_check(), Err(Error::ClockHalted));
+        assert_eq!(
+            ds1307.health_check(),
+            Err(Error::InvalidBcd {
+                register: Register::Day
+            })
+        );
+        assert_eq!(
+            ds1307.health_check(),
+            Err(Error::InvalidBcd {
+                register: Register::Month
+            })
+        );
+        assert_eq!(
+            ds1307.health_check(),
+            Err(Error::DateTime(DateTimeError::InvalidDay))
+        );
 
         ds1307.release_i2c().done();
     }
@@ -1,16 +1,64 @@
 //! # DS1307 Real-Time Clock Driver
 
+use embedded_hal::i2c::{Error as _, ErrorKind, NoAcknowledgeSource};
+use rtc_hal::control::RtcPowerControl;
+use rtc_hal::datetime::{DateTimeError, Weekday};
+
 use crate::{
+    datetime::{DEFAULT_CENTURY_BASE, HourFormat},
     error::Error,
-    registers::{NVRAM_SIZE, OUT_BIT, Register, SQWE_BIT},
+    nvram::{DEFAULT_POWER_MARKER_OFFSET, POWER_MARKER},
+    registers::{MAX_NVRAM_WRITE, NVRAM_SIZE, NVRAM_START, OUT_BIT, Register, SQWE_BIT},
 };
 
-/// DS1307 I2C device address (fixed)
+/// Highest register address of the DS1307 (last NVRAM byte)
+const LAST_ADDRESS: u8 = NVRAM_START + NVRAM_SIZE - 1;
+
+/// Default DS1307 I2C device address
 pub const I2C_ADDR: u8 = 0x68;
 
+/// Static level of the SQW/OUT pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PinLevel {
+    /// Pin driven high (OUT bit set)
+    High,
+    /// Pin driven low (OUT bit cleared)
+    Low,
+}
+
+/// Whether a read-modify-write operation actually wrote the register
+///
+/// Returned by the `*_with_outcome` methods, which skip the write when the
+/// register already holds the requested value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WriteOutcome {
+    /// The register was written with a new value
+    Changed,
+    /// The register already held the requested value, nothing was written
+    Unchanged,
+}
+
 /// DS1307 Real-Time Clock driver
+///
+/// The driver is [`Clone`] when the I2C bus handle is, e.g. a shared bus device
+/// from [`embedded-hal-bus`](https://crates.io/crates/embedded-hal-bus). Each
+/// clone carries its own copy of the driver settings. Cloning a handle that
+/// owns the bus exclusively is usually a mistake, since the clones would then
+/// issue transactions without any coordination.
+#[derive(Clone)]
 pub struct Ds1307<I2C> {
     i2c: I2C,
+    address: u8,
+    power_marker_offset: u8,
+    retries: u8,
+    weekday_epoch: Weekday,
+    century_base: u16,
+    max_transfer: usize,
+    drift_ppm: i32,
+    drift_accumulator: i64,
+    assumed_hour_format: Option<HourFormat>,
 }
 
 impl<I2C: embedded_hal::i2c::I2c> rtc_hal::error::ErrorType for Ds1307<I2C> {
@@ -30,7 +78,247 @@ where
     /// # Returns
     /// New DS1307 driver instance
     pub fn new(i2c: I2C) -> Self {
-        Self { i2c }
+        Self::new_with_address(i2c, I2C_ADDR)
+    }
+
+    /// Create a new DS1307 driver instance and start the oscillator
+    ///
+    /// A factory-fresh DS1307 has its Clock Halt bit set, so the time does not
+    /// advance until it is cleared. Unlike [`new`](Self::new), this talks to
+    /// the device: it clears the CH bit with
+    /// [`start_clock`](rtc_hal::control::RtcPowerControl::start_clock) (one
+    /// read, plus one write if the clock was halted).
+    ///
+    /// On error the I2C peripheral is dropped with the driver; use
+    /// [`new`](Self::new) followed by `start_clock` to keep it.
+    pub fn new_running(i2c: I2C) -> Result<Self, Error<E>> {
+        let mut rtc = Self::new(i2c);
+        rtc.start_clock()?;
+
+        Ok(rtc)
+    }
+
+    /// Create a new DS1307 driver instance with a limited NVRAM transfer size
+    ///
+    /// For I2C controllers that cannot send or receive 57 bytes in one
+    /// transaction. See [`set_max_transfer`](Self::set_max_transfer) for the
+    /// meaning of `max_transfer` and the accepted range; out-of-range values
+    /// return [`Error::InvalidTransferSize`].
+    ///
+    /// This does not talk to the device. On error the I2C peripheral is dropped
+    /// with the driver; use [`new`](Self::new) followed by `set_max_transfer`
+    /// to keep it.
+    pub fn new_with_max_transfer(i2c: I2C, max_transfer: usize) -> Result<Self, Error<E>> {
+        let mut rtc = Self::new(i2c);
+        rtc.set_max_transfer(max_transfer)?;
+
+        Ok(rtc)
+    }
+
+    /// Create a new DS1307 driver instance using a custom I2C address
+    ///
+    /// Useful for DS1307-compatible parts or boards with an address translator
+    /// that do not respond at the default address (0x68).
+    ///
+    /// # Parameters
+    /// * `i2c` - I2C peripheral that implements the embedded-hal I2c trait
+    /// * `address` - 7-bit I2C address of the device
+    ///
+    /// # Returns
+    /// New DS1307 driver instance
+    pub fn new_with_address(i2c: I2C, address: u8) -> Self {
+        Self {
+            i2c,
+            address,
+            power_marker_offset: DEFAULT_POWER_MARKER_OFFSET,
+            retries: 0,
+            weekday_epoch: Weekday::Sunday,
+            century_base: DEFAULT_CENTURY_BASE,
+            max_transfer: MAX_NVRAM_WRITE,
+            drift_ppm: 0,
+            drift_accumulator: 0,
+            assumed_hour_format: None,
+        }
+    }
+
+    /// Returns the 7-bit I2C address used by this driver.
+    pub fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Returns the number of times a failed I2C transaction is retried.
+    pub fn retries(&self) -> u8 {
+        self.retries
+    }
+
+    /// Set how many times a failed I2C transaction is retried before giving up.
+    ///
+    /// Each register access is attempted up to `count + 1` times, and only
+    /// [`Error::I2c`] failures are retried; validation errors are returned
+    /// immediately. The default is `0` (no retries).
+    ///
+    /// Note that a retried write is repeated in full, and a read-modify-write
+    /// sequence retries its read and write independently.
+    pub fn set_retries(&mut self, count: u8) {
+        self.retries = count;
+    }
+
+    /// Returns the weekday stored as `1` in the day of week register.
+    pub fn weekday_epoch(&self) -> Weekday {
+        self.weekday_epoch
+    }
+
+    /// Set the weekday numbering convention of the day of week register.
+    ///
+    /// The DS1307 day of week register counts 1-7 with no fixed meaning.
+    /// `reference` is the weekday stored as `1`, with the following days
+    /// counting up from there. The default is [`Weekday::Sunday`] (Sunday = 1,
+    /// Saturday = 7).
+    ///
+    /// The convention is used whenever the driver writes the day of week
+    /// register (e.g. [`Rtc::set_datetime`](rtc_hal::rtc::Rtc::set_datetime)) or
+    /// interprets it (e.g. [`get_weekday`](Self::get_weekday)).
+    pub fn set_weekday_epoch(&mut self, reference: Weekday) {
+        self.weekday_epoch = reference;
+    }
+
+    /// Returns the maximum number of bytes sent or received in one NVRAM transfer.
+    pub fn max_transfer(&self) -> usize {
+        self.max_transfer
+    }
+
+    /// Limit the size of NVRAM transfers, for I2C controllers with a short
+    /// maximum transfer length.
+    ///
+    /// NVRAM writes are split into transfers of at most `bytes` bytes including
+    /// the register address byte, and reads into transfers of at most `bytes`
+    /// data bytes, each starting at the adjusted NVRAM address. The default
+    /// (57) performs every NVRAM access in a single burst.
+    ///
+    /// Returns [`Error::InvalidTransferSize`] if `bytes` is outside `2..=57`
+    /// (an address byte plus at least one data byte, up to a full NVRAM
+    /// burst), leaving the current setting unchanged.
+    pub fn set_max_transfer(&mut self, bytes: usize) -> Result<(), Error<E>> {
+        if !(2..=MAX_NVRAM_WRITE).contains(&bytes) {
+            return Err(Error::InvalidTransferSize);
+        }
+        self.max_transfer = bytes;
+
+        Ok(())
+    }
+
+    /// Returns the first year of the century window mapped onto the year register.
+    pub fn century_base(&self) -> u16 {
+        self.century_base
+    }
+
+    /// Set the century window mapped onto the two-digit year register.
+    ///
+    /// The DS1307 only stores the last two digits of the year. Register values
+    /// `00`-`99` are read as `base..=base + 99`, and only years in that window
+    /// can be written. The default is [`DEFAULT_CENTURY_BASE`] (2000-2099).
+    ///
+    /// The chip treats years whose two-digit value is divisible by 4 as leap
+    /// years, so its own date rollover around February 29 is only correct when
+    /// `base` is a multiple of 4 and the window does not include 2100 (or
+    /// another non-leap century year).
+    ///
+    /// Returns [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the
+    /// window would start before 1970, the earliest year [`DateTime`] supports,
+    /// or end past `u16::MAX`.
+    ///
+    /// [`DateTime`]: rtc_hal::datetime::DateTime
+    pub fn set_century_base(&mut self, base: u16) -> Result<(), Error<E>> {
+        if !(1970..=u16::MAX - 100).contains(&base) {
+            return Err(Error::DateTime(DateTimeError::InvalidYear));
+        }
+        self.century_base = base;
+
+        Ok(())
+    }
+
+    /// Returns the NVRAM offset of the power-loss marker.
+    ///
+    /// See [`mark_initialized`](Self::mark_initialized).
+    pub fn power_marker_offset(&self) -> u8 {
+        self.power_marker_offset
+    }
+
+    /// Set the NVRAM offset where the power-loss marker is stored.
+    ///
+    /// The marker occupies [`POWER_MARKER`]`.len()` bytes starting at `offset`.
+    /// Returns [`Error::NvramOutOfBounds`] if it would not fit in NVRAM.
+    pub fn set_power_marker_offset(&mut self, offset: u8) -> Result<(), Error<E>> {
+        self.validate_nvram_bounds(offset, POWER_MARKER.len())?;
+        self.power_marker_offset = offset;
+
+        Ok(())
+    }
+
+    /// Returns the hour format the chip is expected to run in, if any.
+    pub fn assumed_hour_format(&self) -> Option<HourFormat> {
+        self.assumed_hour_format
+    }
+
+    /// Declare the hour format the chip is expected to run in.
+    ///
+    /// With `Some(format)`, every read that decodes the hours register
+    /// ([`Rtc::get_datetime`](rtc_hal::rtc::Rtc::get_datetime),
+    /// [`get_hours`](Self::get_hours), ...) checks the 12/24-hour mode bit and
+    /// returns [`Error::HourFormatMismatch`] if the chip was switched to the
+    /// other mode, e.g. by another bus master. With `None` (the default), both
+    /// modes are decoded transparently.
+    ///
+    /// Once an assumption is set, every driver write of the hours register
+    /// updates it to the mode written, so the driver's own reads keep working:
+    /// [`Rtc::set_datetime`](rtc_hal::rtc::Rtc::set_datetime) switches it to
+    /// 24-hour mode, while [`set_datetime_with_format`](Self::set_datetime_with_format) and
+    /// [`set_hour_format`](Self::set_hour_format) switch it to the given format.
+    /// Raw register writes do not update it.
+    pub fn assume_hour_format(&mut self, format: Option<HourFormat>) {
+        self.assumed_hour_format = format;
+    }
+
+    /// Returns the configured crystal error in parts per million.
+    pub fn drift_ppm(&self) -> i32 {
+        self.drift_ppm
+    }
+
+    /// Set the known crystal error used by [`tick_correction`](Self::tick_correction).
+    ///
+    /// A positive `ppm` means the DS1307 runs fast (gains time), a negative one
+    /// that it runs slow. The default is `0` (no correction). Drift accumulated
+    /// so far is kept.
+    pub fn set_drift_ppm(&mut self, ppm: i32) {
+        self.drift_ppm = ppm;
+    }
+
+    /// Account for `elapsed_seconds` of operation and correct the stored time
+    /// once the accumulated drift reaches a whole second.
+    ///
+    /// The DS1307 has no trim register, so the correction is applied in
+    /// software with [`adjust_datetime`](Self::adjust_datetime). Fractional
+    /// drift is carried over to the next call. Returns the number of seconds
+    /// added to the stored time (negative if the clock was set back), `0` if no
+    /// adjustment was due.
+    ///
+    /// If the adjustment fails, the drift stays accumulated and is applied by a
+    /// later call.
+    pub fn tick_correction(&mut self, elapsed_seconds: u32) -> Result<i64, Error<E>> {
+        self.drift_accumulator = self
+            .drift_accumulator
+            .saturating_add(elapsed_seconds as i64 * self.drift_ppm as i64);
+
+        // Accumulator is in microseconds (ppm x seconds)
+        let whole_seconds = self.drift_accumulator / 1_000_000;
+        if whole_seconds == 0 {
+            return Ok(0);
+        }
+
+        self.adjust_datetime(-whole_seconds)?;
+        self.drift_accumulator -= whole_seconds * 1_000_000;
+
+        Ok(-whole_seconds)
     }
 
     /// Returns the underlying I2C bus instance, consuming the driver.
@@ -45,18 +333,65 @@ where
         self.i2c
     }
 
+    /// Perform an I2C write, retrying failed attempts as configured by [`set_retries`](Self::set_retries)
+    fn i2c_write(&mut self, data: &[u8]) -> Result<(), Error<E>> {
+        #[cfg(feature = "log")]
+        if let Some((register_addr, payload)) = data.split_first() {
+            log::trace!(
+                "ds1307: write {:#04x}, {} byte(s)",
+                register_addr,
+                payload.len()
+            );
+        }
+
+        let mut attempts_left = self.retries;
+        loop {
+            match self.i2c.write(self.address, data) {
+                Ok(()) => return Ok(()),
+                Err(_) if attempts_left > 0 => {
+                    #[cfg(feature = "log")]
+                    log::trace!("ds1307: write failed, retrying");
+                    attempts_left -= 1
+                }
+                Err(e) => return Err(Error::I2c(e)),
+            }
+        }
+    }
+
+    /// Perform an I2C write-read, retrying failed attempts as configured by [`set_retries`](Self::set_retries)
+    fn i2c_write_read(&mut self, write: &[u8], buffer: &mut [u8]) -> Result<(), Error<E>> {
+        #[cfg(feature = "log")]
+        if let Some(register_addr) = write.first() {
+            log::trace!(
+                "ds1307: read {:#04x}, {} byte(s)",
+                register_addr,
+                buffer.len()
+            );
+        }
+
+        let mut attempts_left = self.retries;
+        loop {
+            match self.i2c.write_read(self.address, write, buffer) {
+                Ok(()) => return Ok(()),
+                Err(_) if attempts_left > 0 => {
+                    #[cfg(feature = "log")]
+                    log::trace!("ds1307: read failed, retrying");
+                    attempts_left -= 1
+                }
+                Err(e) => return Err(Error::I2c(e)),
+            }
+        }
+    }
+
     /// Write a single byte to a DS1307 register
     pub(crate) fn write_register(&mut self, register: Register, value: u8) -> Result<(), Error<E>> {
-        self.i2c.write(I2C_ADDR, &[register.addr(), value])?;
-
-        Ok(())
+        self.i2c_write(&[register.addr(), value])
     }
 
     /// Read a single byte from a DS1307 register
     pub(crate) fn read_register(&mut self, register: Register) -> Result<u8, Error<E>> {
         let mut data = [0u8; 1];
-        self.i2c
-            .write_read(I2C_ADDR, &[register.addr()], &mut data)?;
+        self.i2c_write_read(&[register.addr()], &mut data)?;
 
         Ok(data[0])
     }
@@ -67,27 +402,47 @@ where
         register: Register,
         buffer: &mut [u8],
     ) -> Result<(), Error<E>> {
-        self.i2c.write_read(I2C_ADDR, &[register.addr()], buffer)?;
+        self.i2c_write_read(&[register.addr()], buffer)
+    }
+
+    /// Check that `len` bytes starting at the raw register address `register_addr`
+    /// stay within the DS1307 address space (0x00-0x3F).
+    ///
+    /// The device wraps around to 0x00 past 0x3F, so a transfer crossing the end
+    /// would silently touch the timekeeping registers.
+    fn validate_address_range(&self, register_addr: u8, len: usize) -> Result<(), Error<E>> {
+        let end = (register_addr as usize)
+            .checked_add(len)
+            .ok_or(Error::InvalidAddress)?;
+        if register_addr > LAST_ADDRESS || end > LAST_ADDRESS as usize + 1 {
+            return Err(Error::InvalidAddress);
+        }
 
         Ok(())
     }
 
     /// Read multiple bytes from DS1307 starting at a raw address
+    ///
+    /// Returns [`Error::InvalidAddress`] if the read would go past 0x3F.
     pub(crate) fn read_bytes_at_address(
         &mut self,
         register_addr: u8,
         buffer: &mut [u8],
     ) -> Result<(), Error<E>> {
-        self.i2c.write_read(I2C_ADDR, &[register_addr], buffer)?;
+        self.validate_address_range(register_addr, buffer.len())?;
 
-        Ok(())
+        self.i2c_write_read(&[register_addr], buffer)
     }
 
     /// Write raw bytes directly to DS1307 via I2C (register address must be first byte)
+    ///
+    /// Returns [`Error::InvalidAddress`] if `data` is empty or the write would go
+    /// past 0x3F.
     pub(crate) fn write_raw_bytes(&mut self, data: &[u8]) -> Result<(), Error<E>> {
-        self.i2c.write(I2C_ADDR, data)?;
+        let (&register_addr, payload) = data.split_first().ok_or(Error::InvalidAddress)?;
+        self.validate_address_range(register_addr, payload.len())?;
 
-        Ok(())
+        self.i2c_write(data)
     }
 
     /// Read-modify-write operation for setting bits
@@ -113,14 +468,8 @@ where
         &mut self,
         register: Register,
         mask: u8,
-    ) -> Result<(), Error<E>> {
-        let current = self.read_register(register)?;
-        let new_value = current | mask;
-        if new_value != current {
-            self.write_register(register, new_value)
-        } else {
-            Ok(())
-        }
+    ) -> Result<WriteOutcome, Error<E>> {
+        self.update_register(register, |current| current | mask)
     }
 
     /// Read-modify-write operation for clearing bits
@@ -146,46 +495,137 @@ where
         &mut self,
         register: Register,
         mask: u8,
-    ) -> Result<(), Error<E>> {
+    ) -> Result<WriteOutcome, Error<E>> {
+        self.update_register(register, |current| current & !mask)
+    }
+
+    /// Read-modify-write of a register with an arbitrary update
+    ///
+    /// Reads `register`, applies `update` to its value and writes the result
+    /// back only if it differs.
+    pub(crate) fn update_register(
+        &mut self,
+        register: Register,
+        update: impl FnOnce(u8) -> u8,
+    ) -> Result<WriteOutcome, Error<E>> {
         let current = self.read_register(register)?;
-        let new_value = current & !mask;
+        let new_value = update(current);
         if new_value != current {
-            self.write_register(register, new_value)
+            self.write_register(register, new_value)?;
+            Ok(WriteOutcome::Changed)
         } else {
-            Ok(())
+            Ok(WriteOutcome::Unchanged)
         }
     }
 
-    /// Set the output pin to a static high state
-    pub fn set_output_high(&mut self) -> Result<(), Error<E>> {
-        let current = self.read_register(Register::Control)?;
-        let mut new_value = current;
+    /// Read the raw value of a named timekeeping or control register.
+    ///
+    /// The value is returned as stored on the chip, e.g. BCD digits with the
+    /// CH bit for [`Register::Seconds`]; see the [`registers`](crate::registers)
+    /// module for the bit flags.
+    pub fn register_value(&mut self, register: Register) -> Result<u8, Error<E>> {
+        self.read_register(register)
+    }
+
+    /// Read the raw value of the control register (0x07).
+    ///
+    /// Bit layout:
+    ///
+    /// | Bit | 7   | 6 | 5 | 4    | 3 | 2 | 1   | 0   |
+    /// |-----|-----|---|---|------|---|---|-----|-----|
+    /// |     | OUT | 0 | 0 | SQWE | 0 | 0 | RS1 | RS0 |
+    ///
+    /// - [`OUT_BIT`](crate::registers::OUT_BIT): output level when the square wave is disabled
+    /// - [`SQWE_BIT`](crate::registers::SQWE_BIT): square wave enable
+    /// - [`RS_MASK`](crate::registers::RS_MASK): square wave rate select
+    ///   (`00` = 1 Hz, `01` = 4.096 kHz, `10` = 8.192 kHz, `11` = 32.768 kHz)
+    pub fn read_control(&mut self) -> Result<u8, Error<E>> {
+        self.read_register(Register::Control)
+    }
 
-        // Disable square wave and set OUT bit high
-        new_value &= !SQWE_BIT;
-        new_value |= OUT_BIT;
+    /// Write a raw value to the control register (0x07) in a single transaction.
+    ///
+    /// See [`read_control`](Self::read_control) for the bit layout. Unused bits
+    /// should be written as `0`.
+    pub fn write_control(&mut self, value: u8) -> Result<(), Error<E>> {
+        self.write_register(Register::Control, value)
+    }
 
-        if new_value != current {
-            self.write_register(Register::Control, new_value)
+    /// Reset the control register (0x07) to its power-on default in a single write.
+    ///
+    /// Writes `0x00`: square wave disabled, OUT low, RS = `00` (1 Hz).
+    pub fn reset_control(&mut self) -> Result<(), Error<E>> {
+        self.write_register(Register::Control, 0x00)
+    }
+
+    /// Drive the SQW/OUT pin to a static `level`
+    ///
+    /// Disables the square wave output and sets the OUT bit accordingly. The
+    /// control register is only written if it changes.
+    pub fn set_output(&mut self, level: PinLevel) -> Result<(), Error<E>> {
+        self.set_output_with_outcome(level).map(|_| ())
+    }
+
+    /// Drive the SQW/OUT pin to a static `level`, reporting whether the control
+    /// register was written
+    ///
+    /// See [`set_output`](Self::set_output).
+    pub fn set_output_with_outcome(&mut self, level: PinLevel) -> Result<WriteOutcome, Error<E>> {
+        // Disable square wave and set OUT bit to the requested level
+        self.update_register(Register::Control, |current| match level {
+            PinLevel::High => (current & !SQWE_BIT) | OUT_BIT,
+            PinLevel::Low => current & !SQWE_BIT & !OUT_BIT,
+        })
+    }
+
+    /// Read the level of the OUT bit in the control register
+    ///
+    /// This is the level the SQW/OUT pin is driven to while the square wave is
+    /// disabled; use [`get_output_level`](Self::get_output_level) to also find
+    /// out whether the square wave is currently enabled.
+    pub fn get_output(&mut self) -> Result<PinLevel, Error<E>> {
+        let value = self.read_register(Register::Control)?;
+
+        Ok(if (value & OUT_BIT) != 0 {
+            PinLevel::High
         } else {
-            Ok(())
-        }
+            PinLevel::Low
+        })
+    }
+
+    /// Set the output pin to a static high state
+    pub fn set_output_high(&mut self) -> Result<(), Error<E>> {
+        self.set_output(PinLevel::High)
     }
 
     /// Set the output pin to a static low state
     pub fn set_output_low(&mut self) -> Result<(), Error<E>> {
-        let current = self.read_register(Register::Control)?;
-        let mut new_value = current;
-
-        // Disable square wave and set OUT bit low
-        new_value &= !SQWE_BIT;
-        new_value &= !OUT_BIT;
+        self.set_output(PinLevel::Low)
+    }
 
-        if new_value != current {
-            self.write_register(Register::Control, new_value)
-        } else {
-            Ok(())
+    /// Read the configured static output level of the SQW/OUT pin
+    ///
+    /// Returns `Some(true)` if the pin is driven high, `Some(false)` if it is
+    /// driven low, and `None` if the square wave output is enabled, in which
+    /// case the OUT bit has no effect on the pin.
+    pub fn get_output_level(&mut self) -> Result<Option<bool>, Error<E>> {
+        let value = self.read_register(Register::Control)?;
+        if (value & SQWE_BIT) != 0 {
+            return Ok(None);
         }
+
+        Ok(Some((value & OUT_BIT) != 0))
+    }
+
+    /// Read the timekeeping and control registers (0x00-0x07) in one burst.
+    ///
+    /// Returns the raw, undecoded register bytes indexed by register address,
+    /// suitable for including in bug reports. This is read-only.
+    pub fn dump_registers(&mut self) -> Result<[u8; 8], Error<E>> {
+        let mut data = [0u8; 8];
+        self.read_register_bytes(Register::Seconds, &mut data)?;
+
+        Ok(data)
     }
 
     /// Validate NVRAM offset and length parameters before accessing memory.
@@ -194,21 +634,57 @@ where
     /// - The starting offset is outside the available NVRAM range
     /// - The requested length goes beyond the end of NVRAM
     pub(crate) fn validate_nvram_bounds(&self, offset: u8, len: usize) -> Result<(), Error<E>> {
+        let out_of_bounds = || Error::NvramOutOfBounds {
+            offset,
+            len,
+            capacity: NVRAM_SIZE,
+        };
+
         // Check if offset is within bounds
         if offset >= NVRAM_SIZE {
-            return Err(Error::NvramOutOfBounds);
+            return Err(out_of_bounds());
         }
 
-        // Check if remaining space is sufficient
-        let remaining_space = NVRAM_SIZE - offset;
-        if len > remaining_space as usize {
-            return Err(Error::NvramOutOfBounds);
+        // Check that the whole range ends within NVRAM, without overflowing
+        let end = (offset as usize)
+            .checked_add(len)
+            .ok_or_else(out_of_bounds)?;
+        if end > NVRAM_SIZE as usize {
+            return Err(out_of_bounds());
         }
 
         Ok(())
     }
 }
 
+impl<I2C> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    /// Check whether a DS1307 responds on the I2C bus.
+    ///
+    /// Performs a single read of the seconds register. Returns `Ok(true)` if the
+    /// device acknowledges, `Ok(false)` if the address is not acknowledged (no
+    /// device present or a wiring fault), and propagates any other bus error.
+    pub fn is_connected(&mut self) -> Result<bool, Error<I2C::Error>> {
+        #[cfg(feature = "log")]
+        log::trace!("ds1307: probe {:#04x}, 1 byte(s)", Register::Seconds.addr());
+
+        let mut data = [0u8; 1];
+        match self
+            .i2c
+            .write_read(self.address, &[Register::Seconds.addr()], &mut data)
+        {
+            Ok(()) => Ok(true),
+            Err(e) => match e.kind() {
+                ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)
+                | ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown) => Ok(false),
+                _ => Err(Error::I2c(e)),
+            },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,6 +710,86 @@ mod tests {
         i2c_mock.done();
     }
 
+    #[test]
+    fn test_new_with_address() {
+        let expectations = vec![
+            I2cTransaction::write(0x50, vec![Register::Control.addr(), 0x10]),
+            I2cTransaction::write_read(0x50, vec![Register::Control.addr()], vec![0x10]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new_with_address(i2c_mock, 0x50);
+        assert_eq!(ds1307.address(), 0x50);
+
+        assert!(ds1307.write_register(Register::Control, 0x10).is_ok());
+        assert_eq!(ds1307.read_register(Register::Control).unwrap(), 0x10);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_new_uses_default_address() {
+        let i2c_mock = I2cMock::new(&[]);
+        let ds1307 = Ds1307::new(i2c_mock);
+        assert_eq!(ds1307.address(), DS1307_ADDR);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_is_connected() {
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![Register::Seconds.addr()],
+            vec![0x00],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.is_connected().unwrap());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_is_connected_address_nack() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x00])
+                .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(!ds1307.is_connected().unwrap());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_is_connected_bus_error() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x00])
+                .with_error(ErrorKind::Bus),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(matches!(
+            ds1307.is_connected(),
+            Err(Error::I2c(ErrorKind::Bus))
+        ));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
     #[test]
     fn test_write_register() {
         let expectations = vec![I2cTransaction::write(
@@ -654,6 +1210,136 @@ mod tests {
         i2c_mock.done();
     }
 
+    #[test]
+    fn test_read_control() {
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![Register::Control.addr()],
+            vec![SQWE_BIT | 0b0000_0011],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.read_control().unwrap(), SQWE_BIT | 0b0000_0011);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_write_control() {
+        let expectations = vec![I2cTransaction::write(
+            DS1307_ADDR,
+            vec![Register::Control.addr(), OUT_BIT | 0b0000_0001],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.write_control(OUT_BIT | 0b0000_0001).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_retries_default_none() {
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.retries(), 0);
+        assert!(matches!(ds1307.reset_control(), Err(Error::I2c(_))));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_retries_recover_transient_error() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x13]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x13])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x13]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+        ds1307.set_retries(2);
+
+        assert_eq!(ds1307.read_control().unwrap(), 0x13);
+        assert!(ds1307.write_control(0x13).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_retries_exhausted() {
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+        ds1307.set_retries(1);
+
+        assert!(matches!(ds1307.reset_control(), Err(Error::I2c(_))));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_clone_shares_bus() {
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x00]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x00]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+        ds1307.set_retries(2);
+
+        let mut other = ds1307.clone();
+        assert_eq!(other.retries(), 2);
+
+        ds1307.reset_control().unwrap();
+        assert_eq!(other.read_control().unwrap(), 0x00);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_reset_control() {
+        let expectations = vec![I2cTransaction::write(
+            DS1307_ADDR,
+            vec![Register::Control.addr(), 0x00],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.reset_control().is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
     #[test]
     fn test_set_output_high_from_sqwe_disabled() {
         let expectations = vec![
@@ -860,6 +1546,64 @@ mod tests {
         i2c_mock.done();
     }
 
+    #[test]
+    fn test_get_output_level() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![OUT_BIT]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x03]),
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Control.addr()],
+                vec![SQWE_BIT | OUT_BIT],
+            ),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.get_output_level().unwrap(), Some(true));
+        assert_eq!(ds1307.get_output_level().unwrap(), Some(false));
+        // Square wave active: OUT level is meaningless
+        assert_eq!(ds1307.get_output_level().unwrap(), None);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_get_output_level_read_error() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.get_output_level().is_err());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_dump_registers() {
+        let registers = [0x80, 0x59, 0x23, 0x04, 0x15, 0x08, 0x23, 0x13];
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![Register::Seconds.addr()],
+            registers.to_vec(),
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.dump_registers().unwrap(), registers);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
     #[test]
     fn test_output_functions_preserve_other_bits() {
         // Test that output functions preserve other control register bits
@@ -886,4 +1630,234 @@ mod tests {
         let mut i2c_mock = ds1307.release_i2c();
         i2c_mock.done();
     }
+
+    #[test]
+    fn test_raw_access_rejects_addresses_past_end() {
+        let expectations = [
+            I2cTransaction::write_read(DS1307_ADDR, vec![0x3F], vec![0xAA]),
+            I2cTransaction::write(DS1307_ADDR, vec![0x3E, 0x01, 0x02]),
+        ];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        // Last byte of the address space is still reachable
+        let mut buffer = [0u8; 1];
+        ds1307.read_bytes_at_address(0x3F, &mut buffer).unwrap();
+        assert_eq!(buffer, [0xAA]);
+        ds1307.write_raw_bytes(&[0x3E, 0x01, 0x02]).unwrap();
+
+        // Starting past the end, or running over it, is rejected without bus access
+        assert_eq!(
+            ds1307.read_bytes_at_address(0x40, &mut buffer),
+            Err(Error::InvalidAddress)
+        );
+        let mut buffer = [0u8; 2];
+        assert_eq!(
+            ds1307.read_bytes_at_address(0x3F, &mut buffer),
+            Err(Error::InvalidAddress)
+        );
+        assert_eq!(
+            ds1307.write_raw_bytes(&[0x80, 0x00]),
+            Err(Error::InvalidAddress)
+        );
+        assert_eq!(
+            ds1307.write_raw_bytes(&[0x3F, 0x01, 0x02]),
+            Err(Error::InvalidAddress)
+        );
+        assert_eq!(ds1307.write_raw_bytes(&[]), Err(Error::InvalidAddress));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_output_and_get_output() {
+        let expectations = [
+            // Square wave enabled at 4.096 kHz, OUT low
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x11]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x81]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x81]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x81]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x01]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x01]),
+        ];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        ds1307.set_output(PinLevel::High).unwrap();
+        assert_eq!(ds1307.get_output().unwrap(), PinLevel::High);
+        ds1307.set_output(PinLevel::Low).unwrap();
+        assert_eq!(ds1307.get_output().unwrap(), PinLevel::Low);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_new_running_clears_clock_halt() {
+        let expectations = [
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x80]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Seconds.addr(), 0x00]),
+        ];
+
+        let ds1307 = Ds1307::new_running(I2cMock::new(&expectations)).unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_new_running_i2c_error() {
+        let expectations =
+            [
+                I2cTransaction::write_read(DS1307_ADDR, vec![Register::Seconds.addr()], vec![0x00])
+                    .with_error(embedded_hal::i2c::ErrorKind::Other),
+            ];
+        let mut i2c_mock = I2cMock::new(&expectations);
+
+        let result = Ds1307::new_running(i2c_mock.clone());
+        assert_eq!(
+            result.err(),
+            Some(Error::I2c(embedded_hal::i2c::ErrorKind::Other))
+        );
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_tick_correction_accumulates_fractional_drift() {
+        // 20 ppm fast: one second is gained every 50 000 s
+        let expectations = [
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Seconds.addr()],
+                vec![0x30, 0x00, 0x12, 0x03, 0x31, 0x12, 0x24],
+            ),
+            I2cTransaction::write(
+                DS1307_ADDR,
+                vec![
+                    Register::Seconds.addr(),
+                    0x29,
+                    0x00,
+                    0x12,
+                    0x03,
+                    0x31,
+                    0x12,
+                    0x24,
+                ],
+            ),
+        ];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        ds1307.set_drift_ppm(20);
+        assert_eq!(ds1307.drift_ppm(), 20);
+
+        assert_eq!(ds1307.tick_correction(30_000).unwrap(), 0);
+        assert_eq!(ds1307.tick_correction(30_000).unwrap(), -1);
+        // 10 000 s carried over, plus 30 000 s, is still short of a second
+        assert_eq!(ds1307.tick_correction(30_000).unwrap(), 0);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_tick_correction_keeps_12h_mode() {
+        // 10:00:00 AM, 1 ppm fast for 1 000 000 s: set back to 9:59:59 AM
+        let expectations = [
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Seconds.addr()],
+                vec![0x00, 0x00, 0b0101_0000, 0x03, 0x31, 0x12, 0x24],
+            ),
+            I2cTransaction::write(
+                DS1307_ADDR,
+                vec![
+                    Register::Seconds.addr(),
+                    0x59,
+                    0x59,
+                    0b0100_1001,
+                    0x03,
+                    0x31,
+                    0x12,
+                    0x24,
+                ],
+            ),
+        ];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        ds1307.assume_hour_format(Some(HourFormat::TwelveHour));
+        ds1307.set_drift_ppm(1);
+
+        assert_eq!(ds1307.tick_correction(1_000_000).unwrap(), -1);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_tick_correction_slow_clock() {
+        let expectations = [
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Seconds.addr()],
+                vec![0x59, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24],
+            ),
+            // 2025-01-01 00:00:01, a Wednesday
+            I2cTransaction::write(
+                DS1307_ADDR,
+                vec![
+                    Register::Seconds.addr(),
+                    0x01,
+                    0x00,
+                    0x00,
+                    0x04,
+                    0x01,
+                    0x01,
+                    0x25,
+                ],
+            ),
+        ];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        ds1307.set_drift_ppm(-1_000_000);
+
+        assert_eq!(ds1307.tick_correction(2).unwrap(), 2);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_tick_correction_zero_ppm() {
+        let mut ds1307 = Ds1307::new(I2cMock::new(&[]));
+
+        assert_eq!(ds1307.tick_correction(u32::MAX).unwrap(), 0);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_register_value() {
+        let expectations = [
+            I2cTransaction::write_read(DS1307_ADDR, vec![0x00], vec![0x85]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![0x06], vec![0x24]),
+        ];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        assert_eq!(ds1307.register_value(Register::Seconds).unwrap(), 0x85);
+        assert_eq!(ds1307.register_value(Register::Year).unwrap(), 0x24);
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_output_with_outcome() {
+        let expectations = [
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x10]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x80]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x80]),
+        ];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        assert_eq!(
+            ds1307.set_output_with_outcome(PinLevel::High).unwrap(),
+            WriteOutcome::Changed
+        );
+        assert_eq!(
+            ds1307.set_output_with_outcome(PinLevel::High).unwrap(),
+            WriteOutcome::Unchanged
+        );
+
+        ds1307.release_i2c().done();
+    }
 }
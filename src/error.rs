@@ -5,6 +5,8 @@
 
 use rtc_hal::datetime::DateTimeError;
 
+use crate::registers::Register;
+
 /// DS1307 driver errors
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -20,8 +22,36 @@ where
     UnsupportedSqwFrequency,
     /// Invalid date/time parameters provided by user
     DateTime(DateTimeError),
-    /// NVRAM write would exceed available space
-    NvramOutOfBounds,
+    /// NVRAM access would exceed available space
+    NvramOutOfBounds {
+        /// Requested starting offset within NVRAM
+        offset: u8,
+        /// Requested number of bytes
+        len: usize,
+        /// Size of the NVRAM in bytes
+        capacity: u8,
+    },
+    /// The oscillator is halted (CH bit set), so the stored time is not trustworthy
+    ClockHalted,
+    /// NVRAM data failed checksum verification
+    NvramChecksum,
+    /// A timekeeping register read from the device does not hold a valid BCD value
+    InvalidBcd {
+        /// The register holding the invalid value
+        register: Register,
+    },
+    /// Data read back after a write does not match what was written
+    VerifyMismatch,
+    /// A caller-provided buffer is too small for the operation
+    BufferTooSmall,
+    /// The device did not read back a value written to it
+    SelfTestFailed,
+    /// The hours register is in a different 12/24-hour mode than the driver assumes
+    HourFormatMismatch,
+    /// A page size of zero was passed to an NVRAM page walk
+    InvalidPageSize,
+    /// The maximum I2C transfer size is outside the supported range
+    InvalidTransferSize,
 }
 
 impl<I2cError> core::fmt::Display for Error<I2cError>
@@ -31,17 +61,50 @@ where
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Error::I2c(e) => write!(f, "I2C communication error: {e}"),
-            Error::InvalidAddress => write!(f, "Invalid NVRAM address"),
+            Error::InvalidAddress => write!(f, "Invalid register address"),
             Error::DateTime(e) => write!(f, "Invalid date/time values: {e}"),
             Error::UnsupportedSqwFrequency => write!(f, "Unsupported square wave frequency"),
-            Error::NvramOutOfBounds => write!(f, "NVRAM operation out of bounds"),
+            Error::NvramOutOfBounds {
+                offset,
+                len,
+                capacity,
+            } => write!(
+                f,
+                "NVRAM operation out of bounds: offset {offset} + len {len} exceeds capacity {capacity}"
+            ),
+            Error::ClockHalted => write!(f, "Clock is halted"),
+            Error::NvramChecksum => write!(f, "NVRAM checksum mismatch"),
+            Error::InvalidBcd { register } => {
+                write!(f, "Invalid BCD value in {register:?} register")
+            }
+            Error::VerifyMismatch => write!(f, "Read-back verification failed"),
+            Error::BufferTooSmall => write!(f, "Buffer too small"),
+            Error::SelfTestFailed => write!(f, "Device self-test failed"),
+            Error::HourFormatMismatch => write!(f, "Unexpected 12/24-hour mode"),
+            Error::InvalidPageSize => write!(f, "Invalid NVRAM page size"),
+            Error::InvalidTransferSize => write!(f, "Invalid maximum transfer size"),
         }
     }
 }
 
-impl<I2cError> core::error::Error for Error<I2cError> where
-    I2cError: core::fmt::Debug + core::fmt::Display
+/// The inner I2C error of [`Error::I2c`] is exposed as the error source, which
+/// requires the I2C error type to implement [`core::error::Error`] (the same
+/// trait as `std::error::Error`).
+///
+/// `Error<E>` is `Send + Sync + 'static` whenever `E` is, so `?` converts it
+/// into `anyhow::Error`, `eyre::Report` or `Box<dyn std::error::Error + Send + Sync>`
+/// through their blanket `From` impls, with the I2C error still reachable
+/// through the source chain (e.g. `anyhow::Error::chain`).
+impl<I2cError> core::error::Error for Error<I2cError>
+where
+    I2cError: core::error::Error + 'static,
 {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::I2c(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 /// Converts an [`I2cError`] into an [`Error`] by wrapping it in the
@@ -65,8 +128,17 @@ where
             Error::I2c(_) => rtc_hal::error::ErrorKind::Bus,
             Error::InvalidAddress => rtc_hal::error::ErrorKind::InvalidAddress,
             Error::DateTime(_) => rtc_hal::error::ErrorKind::InvalidDateTime,
-            Error::NvramOutOfBounds => rtc_hal::error::ErrorKind::NvramOutOfBounds,
+            Error::NvramOutOfBounds { .. } => rtc_hal::error::ErrorKind::NvramOutOfBounds,
             Error::UnsupportedSqwFrequency => rtc_hal::error::ErrorKind::UnsupportedSqwFrequency,
+            Error::ClockHalted => rtc_hal::error::ErrorKind::Other,
+            Error::NvramChecksum => rtc_hal::error::ErrorKind::Other,
+            Error::InvalidBcd { .. } => rtc_hal::error::ErrorKind::InvalidDateTime,
+            Error::VerifyMismatch => rtc_hal::error::ErrorKind::Other,
+            Error::BufferTooSmall => rtc_hal::error::ErrorKind::Other,
+            Error::SelfTestFailed => rtc_hal::error::ErrorKind::Other,
+            Error::HourFormatMismatch => rtc_hal::error::ErrorKind::Other,
+            Error::InvalidPageSize => rtc_hal::error::ErrorKind::Other,
+            Error::InvalidTransferSize => rtc_hal::error::ErrorKind::Other,
         }
     }
 }
@@ -105,8 +177,50 @@ mod tests {
         assert_eq!(e.kind(), ErrorKind::UnsupportedSqwFrequency);
 
         // NvramOutOfBounds
-        let e: Error<&str> = Error::NvramOutOfBounds;
+        let e: Error<&str> = Error::NvramOutOfBounds {
+            offset: 50,
+            len: 10,
+            capacity: 56,
+        };
         assert_eq!(e.kind(), ErrorKind::NvramOutOfBounds);
+
+        // ClockHalted
+        let e: Error<&str> = Error::ClockHalted;
+        assert_eq!(e.kind(), ErrorKind::Other);
+
+        // NvramChecksum
+        let e: Error<&str> = Error::NvramChecksum;
+        assert_eq!(e.kind(), ErrorKind::Other);
+
+        // InvalidBcd
+        let e: Error<&str> = Error::InvalidBcd {
+            register: Register::Month,
+        };
+        assert_eq!(e.kind(), ErrorKind::InvalidDateTime);
+
+        // VerifyMismatch
+        let e: Error<&str> = Error::VerifyMismatch;
+        assert_eq!(e.kind(), ErrorKind::Other);
+
+        // BufferTooSmall
+        let e: Error<&str> = Error::BufferTooSmall;
+        assert_eq!(e.kind(), ErrorKind::Other);
+
+        // SelfTestFailed
+        let e: Error<&str> = Error::SelfTestFailed;
+        assert_eq!(e.kind(), ErrorKind::Other);
+
+        // HourFormatMismatch
+        let e: Error<&str> = Error::HourFormatMismatch;
+        assert_eq!(e.kind(), ErrorKind::Other);
+
+        // InvalidPageSize
+        let e: Error<&str> = Error::InvalidPageSize;
+        assert_eq!(e.kind(), ErrorKind::Other);
+
+        // InvalidTransferSize
+        let e: Error<&str> = Error::InvalidTransferSize;
+        assert_eq!(e.kind(), ErrorKind::Other);
     }
 
     #[derive(Debug, PartialEq, Eq)]
@@ -131,7 +245,7 @@ mod tests {
                 }),
                 "I2C communication error: I2C Error 1: test",
             ),
-            (Error::InvalidAddress, "Invalid NVRAM address"),
+            (Error::InvalidAddress, "Invalid register address"),
             (
                 Error::DateTime(DateTimeError::InvalidMonth),
                 "Invalid date/time values: invalid month",
@@ -140,11 +254,91 @@ mod tests {
                 Error::UnsupportedSqwFrequency,
                 "Unsupported square wave frequency",
             ),
-            (Error::NvramOutOfBounds, "NVRAM operation out of bounds"),
+            (
+                Error::NvramOutOfBounds {
+                    offset: 50,
+                    len: 10,
+                    capacity: 56,
+                },
+                "NVRAM operation out of bounds: offset 50 + len 10 exceeds capacity 56",
+            ),
+            (Error::ClockHalted, "Clock is halted"),
+            (Error::NvramChecksum, "NVRAM checksum mismatch"),
+            (
+                Error::InvalidBcd {
+                    register: Register::Month,
+                },
+                "Invalid BCD value in Month register",
+            ),
+            (Error::VerifyMismatch, "Read-back verification failed"),
+            (Error::BufferTooSmall, "Buffer too small"),
+            (Error::SelfTestFailed, "Device self-test failed"),
+            (Error::HourFormatMismatch, "Unexpected 12/24-hour mode"),
+            (Error::InvalidPageSize, "Invalid NVRAM page size"),
+            (Error::InvalidTransferSize, "Invalid maximum transfer size"),
         ];
 
         for (error, expected) in errors {
             assert_eq!(format!("{error}"), expected);
         }
     }
+
+    impl core::error::Error for MockI2cError {}
+
+    #[test]
+    fn test_error_source() {
+        use core::error::Error as _;
+
+        let e = Error::I2c(MockI2cError {
+            code: 2,
+            message: "nack",
+        });
+        let source = e.source().expect("I2c variant should expose its source");
+        assert_eq!(source.to_string(), "I2C Error 2: nack");
+
+        let e: Error<MockI2cError> = Error::NvramOutOfBounds {
+            offset: 0,
+            len: 57,
+            capacity: 56,
+        };
+        assert!(e.source().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_boxed_std_error() {
+        let boxed: Box<dyn std::error::Error> = Box::new(Error::<MockI2cError>::ClockHalted);
+        assert_eq!(boxed.to_string(), "Clock is halted");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_question_mark_into_boxed_error() {
+        fn app() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            let result: Result<(), Error<MockI2cError>> = Err(Error::I2c(MockI2cError {
+                code: 3,
+                message: "arbitration lost",
+            }));
+            result?;
+            Ok(())
+        }
+
+        let e = app().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "I2C communication error: I2C Error 3: arbitration lost"
+        );
+        // Walk the chain like `anyhow::Error::chain` does
+        let chain: Vec<String> =
+            std::iter::successors(Some(&*e as &dyn std::error::Error), |e| e.source())
+                .map(|e| e.to_string())
+                .collect();
+        assert_eq!(
+            chain,
+            [
+                "I2C communication error: I2C Error 3: arbitration lost",
+                "I2C Error 3: arbitration lost",
+            ]
+        );
+    }
 }
@@ -1,19 +1,36 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![deny(unsafe_code)]
 #![warn(missing_docs)]
 #![cfg_attr(not(doctest), doc = include_str!("../README.md"))]
 
+#[cfg(feature = "chrono")]
+pub mod chrono_support;
+pub mod config;
 pub mod control;
 pub mod datetime;
 mod ds1307;
 pub mod error;
 pub mod nvram;
 pub mod registers;
+pub mod shared;
 pub mod square_wave;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "time")]
+pub mod time_support;
 
 // Re-export Ds1307
-pub use ds1307::Ds1307;
+pub use ds1307::{Ds1307, PinLevel, WriteOutcome};
+
+// Re-export SharedDs1307
+pub use shared::SharedDs1307;
+
+// Re-export Ds1307Config
+pub use config::Ds1307Config;
+
+// Re-export NVRAM geometry and register names
+pub use registers::{NVRAM_SIZE, NVRAM_START, Register};
 
 // Re-export RTC HAL
 pub use rtc_hal::{datetime::DateTime, rtc::Rtc};
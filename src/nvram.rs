@@ -7,9 +7,20 @@ pub use rtc_hal::nvram::RtcNvram;
 
 use crate::{
     Ds1307,
+    error::Error,
     registers::{MAX_NVRAM_WRITE, NVRAM_SIZE, NVRAM_START},
 };
 
+/// Magic bytes written by [`Ds1307::mark_initialized`] to detect power loss
+pub const POWER_MARKER: [u8; 4] = [0xD5, 0x13, 0x07, 0xA5];
+
+/// Default NVRAM offset of the power-loss marker (52), reserving the last four
+/// NVRAM bytes (0x3C-0x3F)
+pub const DEFAULT_POWER_MARKER_OFFSET: u8 = NVRAM_SIZE - POWER_MARKER.len() as u8;
+
+/// NVRAM offset of the scratch byte used by [`Ds1307::self_test`]
+pub const SELF_TEST_OFFSET: u8 = 0;
+
 impl<I2C> RtcNvram for Ds1307<I2C>
 where
     I2C: embedded_hal::i2c::I2c,
@@ -19,7 +30,8 @@ where
     /// - `offset`: starting NVRAM address (0..55)
     /// - `buffer`: output buffer to store the read data
     ///
-    /// Performs a sequential read starting at `NVRAM_START + offset`.
+    /// Performs a sequential read starting at `NVRAM_START + offset`, split
+    /// into several reads if it exceeds [`max_transfer`](Ds1307::max_transfer).
     fn read_nvram(&mut self, offset: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
         if buffer.is_empty() {
             return Ok(());
@@ -27,10 +39,7 @@ where
 
         self.validate_nvram_bounds(offset, buffer.len())?;
 
-        let nvram_addr = NVRAM_START + offset;
-        self.read_bytes_at_address(nvram_addr, buffer)?;
-
-        Ok(())
+        self.read_nvram_chunks(offset, buffer)
     }
 
     /// Write data into DS1307 NVRAM.
@@ -38,7 +47,8 @@ where
     /// - `offset`: starting NVRAM address (0..55)
     /// - `data`: slice containing data to write
     ///
-    /// Uses either single-byte write or burst write depending on length.
+    /// Uses a single burst write, split into several writes if it exceeds
+    /// [`max_transfer`](Ds1307::max_transfer).
     fn write_nvram(&mut self, offset: u8, data: &[u8]) -> Result<(), Self::Error> {
         if data.is_empty() {
             return Ok(());
@@ -46,19 +56,391 @@ where
 
         self.validate_nvram_bounds(offset, data.len())?;
 
-        // Burst write
+        self.write_nvram_chunks(offset, data)
+    }
+
+    /// Return the size of DS1307 NVRAM in bytes (56).
+    fn nvram_size(&self) -> u16 {
+        NVRAM_SIZE as u16
+    }
+}
+
+/// Compute a CRC-8 (polynomial 0x07, initial value 0x00) over `data`.
+///
+/// Bitwise implementation without a lookup table, to keep code size small.
+fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+impl<I2C, E> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Write `data` to NVRAM at `offset`, in transfers of at most
+    /// [`max_transfer`](Self::max_transfer) bytes including the address byte.
+    ///
+    /// The caller must have validated the NVRAM bounds.
+    fn write_nvram_chunks(&mut self, offset: u8, data: &[u8]) -> Result<(), Error<E>> {
         let mut buffer = [0u8; MAX_NVRAM_WRITE];
-        buffer[0] = NVRAM_START + offset;
-        buffer[1..data.len() + 1].copy_from_slice(data);
+        let mut address = NVRAM_START + offset;
+
+        for chunk in data.chunks(self.max_transfer() - 1) {
+            buffer[0] = address;
+            buffer[1..chunk.len() + 1].copy_from_slice(chunk);
+            self.write_raw_bytes(&buffer[..chunk.len() + 1])?;
+
+            address += chunk.len() as u8;
+        }
+
+        Ok(())
+    }
+
+    /// Read NVRAM at `offset` into `buffer`, in transfers of at most
+    /// [`max_transfer`](Self::max_transfer) bytes.
+    ///
+    /// The caller must have validated the NVRAM bounds.
+    fn read_nvram_chunks(&mut self, offset: u8, buffer: &mut [u8]) -> Result<(), Error<E>> {
+        let mut address = NVRAM_START + offset;
+
+        for chunk in buffer.chunks_mut(self.max_transfer()) {
+            self.read_bytes_at_address(address, chunk)?;
+
+            address += chunk.len() as u8;
+        }
+
+        Ok(())
+    }
+
+    /// Fill the entire DS1307 NVRAM (0x08-0x3F) with `value`.
+    ///
+    /// All 56 bytes are written in a single burst, unless a smaller
+    /// [`max_transfer`](Self::max_transfer) is configured.
+    pub fn fill_nvram(&mut self, value: u8) -> Result<(), Error<E>> {
+        self.write_nvram_chunks(0, &[value; NVRAM_SIZE as usize])
+    }
+
+    /// Clear the entire DS1307 NVRAM (0x08-0x3F) by writing zeros.
+    pub fn clear_nvram(&mut self) -> Result<(), Error<E>> {
+        self.fill_nvram(0x00)
+    }
+
+    /// Overwrite the entire DS1307 NVRAM (0x08-0x3F) with `data`.
+    ///
+    /// All 56 bytes are written in a single burst, unless a smaller
+    /// [`max_transfer`](Self::max_transfer) is configured. The array type makes
+    /// a length mismatch impossible, so no bounds check is needed.
+    pub fn write_nvram_all(&mut self, data: &[u8; NVRAM_SIZE as usize]) -> Result<(), Error<E>> {
+        self.write_nvram_chunks(0, data)
+    }
+
+    /// Read the entire DS1307 NVRAM (0x08-0x3F) in one burst (or in
+    /// [`max_transfer`](Self::max_transfer)-sized chunks).
+    pub fn read_nvram_all(&mut self) -> Result<[u8; NVRAM_SIZE as usize], Error<E>> {
+        let mut data = [0u8; NVRAM_SIZE as usize];
+        self.read_nvram_chunks(0, &mut data)?;
+
+        Ok(data)
+    }
+
+    /// Walk the entire NVRAM in pages of `page_size` bytes.
+    ///
+    /// Reads each page in turn and calls `f` with its NVRAM offset and
+    /// contents. The last page is short if `page_size` does not divide 56.
+    /// Returns [`Error::InvalidPageSize`] before any bus access if `page_size`
+    /// is 0; a read error stops the walk, after the pages already passed to `f`.
+    pub fn for_each_page<F>(&mut self, page_size: u8, mut f: F) -> Result<(), Error<E>>
+    where
+        F: FnMut(u8, &[u8]),
+    {
+        if page_size == 0 {
+            return Err(Error::InvalidPageSize);
+        }
+
+        let mut page = [0u8; NVRAM_SIZE as usize];
+        let mut offset = 0u8;
+        while offset < NVRAM_SIZE {
+            let len = page_size.min(NVRAM_SIZE - offset) as usize;
+            self.read_nvram_chunks(offset, &mut page[..len])?;
+            f(offset, &page[..len]);
+
+            offset += len as u8;
+        }
+
+        Ok(())
+    }
+
+    /// Read the entire DS1307 NVRAM (0x08-0x3F), see
+    /// [`read_nvram_all`](Self::read_nvram_all).
+    ///
+    /// Intended for diagnostics; see also
+    /// [`dump_registers`](Self::dump_registers).
+    pub fn dump_nvram(&mut self) -> Result<[u8; NVRAM_SIZE as usize], Error<E>> {
+        self.read_nvram_all()
+    }
+
+    /// Record that the clock has been set by writing [`POWER_MARKER`] to NVRAM.
+    ///
+    /// The marker occupies four bytes starting at
+    /// [`power_marker_offset`](Self::power_marker_offset), which defaults to
+    /// [`DEFAULT_POWER_MARKER_OFFSET`] (the last four NVRAM bytes). Avoid storing
+    /// application data there, or move the marker with
+    /// [`set_power_marker_offset`](Self::set_power_marker_offset). Note that
+    /// [`fill_nvram`](Self::fill_nvram) and [`clear_nvram`](Self::clear_nvram)
+    /// overwrite the marker.
+    pub fn mark_initialized(&mut self) -> Result<(), Error<E>> {
+        self.write_nvram_chunks(self.power_marker_offset(), &POWER_MARKER)
+    }
+
+    /// Check whether the stored time may have been lost since
+    /// [`mark_initialized`](Self::mark_initialized) was called.
+    ///
+    /// Returns `Ok(true)` if the Clock Halt bit is set or the NVRAM marker is
+    /// missing, which is the case after the backup supply was lost.
+    pub fn was_power_lost(&mut self) -> Result<bool, Error<E>> {
+        if self.read_clock_halt()? {
+            return Ok(true);
+        }
+
+        let mut marker = [0u8; POWER_MARKER.len()];
+        self.read_nvram_chunks(self.power_marker_offset(), &mut marker)?;
 
-        self.write_raw_bytes(&buffer[..data.len() + 1])?;
+        Ok(marker != POWER_MARKER)
+    }
+
+    /// Check that the device stores what is written to it.
+    ///
+    /// Reads the NVRAM byte at [`SELF_TEST_OFFSET`], writes its bitwise
+    /// complement, reads it back and then restores the original value. A
+    /// device whose reads are stuck at 0x00 or 0xFF, as seen on some counterfeit
+    /// modules, fails this check with [`Error::SelfTestFailed`].
+    ///
+    /// The original byte is restored even if the readback is wrong; bus errors
+    /// abort the test immediately and may leave the complement in place.
+    pub fn self_test(&mut self) -> Result<(), Error<E>> {
+        let mut original = [0u8; 1];
+        self.read_nvram_chunks(SELF_TEST_OFFSET, &mut original)?;
+
+        let pattern = [!original[0]];
+        self.write_nvram_chunks(SELF_TEST_OFFSET, &pattern)?;
+
+        let mut readback = [0u8; 1];
+        self.read_nvram_chunks(SELF_TEST_OFFSET, &mut readback)?;
+
+        self.write_nvram_chunks(SELF_TEST_OFFSET, &original)?;
+
+        if readback != pattern {
+            return Err(Error::SelfTestFailed);
+        }
+
+        Ok(())
+    }
+
+    /// Write data into NVRAM, using a caller-provided scratch buffer for the transfer.
+    ///
+    /// Behaves like [`RtcNvram::write_nvram`], but assembles each I2C transfer
+    /// (register address followed by data) in `scratch` instead of a 57-byte
+    /// stack buffer. Returns [`Error::BufferTooSmall`] if `scratch` cannot hold
+    /// one transfer: `data.len() + 1` bytes, or fewer if a smaller
+    /// [`max_transfer`](Self::max_transfer) is configured.
+    pub fn write_nvram_in(
+        &mut self,
+        offset: u8,
+        data: &[u8],
+        scratch: &mut [u8],
+    ) -> Result<(), Error<E>> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        self.validate_nvram_bounds(offset, data.len())?;
+        let chunk_len = data.len().min(self.max_transfer() - 1);
+        if scratch.len() < chunk_len + 1 {
+            return Err(Error::BufferTooSmall);
+        }
+
+        let mut address = NVRAM_START + offset;
+        for chunk in data.chunks(chunk_len) {
+            let buffer = &mut scratch[..chunk.len() + 1];
+            buffer[0] = address;
+            buffer[1..].copy_from_slice(chunk);
+            self.write_raw_bytes(buffer)?;
+
+            address += chunk.len() as u8;
+        }
+
+        Ok(())
+    }
+
+    /// Write data into NVRAM followed by a trailing CRC-8 checksum byte.
+    ///
+    /// Occupies `data.len() + 1` bytes starting at `offset`. Use
+    /// [`read_nvram_checked`](Self::read_nvram_checked) to read it back.
+    pub fn write_nvram_checked(&mut self, offset: u8, data: &[u8]) -> Result<(), Error<E>> {
+        self.validate_nvram_bounds(offset, data.len() + 1)?;
+
+        // Burst write of data + checksum
+        let mut buffer = [0u8; NVRAM_SIZE as usize];
+        buffer[..data.len()].copy_from_slice(data);
+        buffer[data.len()] = crc8(data);
+
+        self.write_nvram_chunks(offset, &buffer[..data.len() + 1])
+    }
+
+    /// Read data written by [`write_nvram_checked`](Self::write_nvram_checked)
+    /// and verify its trailing CRC-8 checksum.
+    ///
+    /// Reads `buffer.len() + 1` bytes starting at `offset`. Returns
+    /// [`Error::NvramChecksum`] if the stored checksum does not match, in which
+    /// case the contents of `buffer` are unspecified.
+    pub fn read_nvram_checked(&mut self, offset: u8, buffer: &mut [u8]) -> Result<(), Error<E>> {
+        self.validate_nvram_bounds(offset, buffer.len() + 1)?;
+
+        let mut raw = [0u8; NVRAM_SIZE as usize];
+        let raw = &mut raw[..buffer.len() + 1];
+        self.read_nvram_chunks(offset, raw)?;
+
+        let (data, checksum) = raw.split_at(buffer.len());
+        buffer.copy_from_slice(data);
+        if crc8(data) != checksum[0] {
+            return Err(Error::NvramChecksum);
+        }
 
         Ok(())
     }
 
+    /// Replace the NVRAM bytes at `offset` with `new`, returning the previous
+    /// contents in `old`.
+    ///
+    /// This is a read followed by a write, not an atomic operation: another bus
+    /// master, or a power loss between the two transfers, can observe or leave
+    /// the old contents. Returns [`Error::BufferTooSmall`] if `old` and `new`
+    /// differ in length, and [`Error::NvramOutOfBounds`] if the range does not
+    /// fit in NVRAM; in both cases nothing is transferred.
+    pub fn swap_nvram(&mut self, offset: u8, new: &[u8], old: &mut [u8]) -> Result<(), Error<E>> {
+        if old.len() != new.len() {
+            return Err(Error::BufferTooSmall);
+        }
+        if new.is_empty() {
+            return Ok(());
+        }
+
+        self.validate_nvram_bounds(offset, new.len())?;
+
+        self.read_nvram_chunks(offset, old)?;
+        self.write_nvram_chunks(offset, new)
+    }
+
+    /// Read as much of `buffer` as fits in NVRAM starting at `offset`.
+    ///
+    /// Unlike [`RtcNvram::read_nvram`], a read that runs past the end of NVRAM
+    /// is truncated instead of rejected: only the prefix of `buffer` that fits
+    /// is filled, and the number of bytes actually read is returned. Returns
+    /// `Ok(0)` without touching the bus if `offset` is already out of range or
+    /// `buffer` is empty.
+    pub fn read_nvram_clamped(&mut self, offset: u8, buffer: &mut [u8]) -> Result<usize, Error<E>> {
+        if offset >= NVRAM_SIZE {
+            return Ok(0);
+        }
+
+        let len = buffer.len().min((NVRAM_SIZE - offset) as usize);
+        if len == 0 {
+            return Ok(0);
+        }
+
+        self.read_nvram_chunks(offset, &mut buffer[..len])?;
+
+        Ok(len)
+    }
+
+    /// Write a plain-old-data value into NVRAM at `offset`.
+    ///
+    /// The value is stored using its in-memory byte representation. Returns
+    /// [`Error::NvramOutOfBounds`] if `offset + size_of::<T>()` exceeds the
+    /// 56-byte NVRAM.
+    #[cfg(feature = "bytemuck")]
+    pub fn write_nvram_value<T: bytemuck::Pod>(
+        &mut self,
+        offset: u8,
+        value: &T,
+    ) -> Result<(), Error<E>> {
+        let bytes = bytemuck::bytes_of(value);
+        self.validate_nvram_bounds(offset, bytes.len())?;
+
+        self.write_nvram(offset, bytes)
+    }
+
+    /// Read a plain-old-data value from NVRAM at `offset`.
+    ///
+    /// Returns [`Error::NvramOutOfBounds`] if `offset + size_of::<T>()` exceeds
+    /// the 56-byte NVRAM.
+    #[cfg(feature = "bytemuck")]
+    pub fn read_nvram_value<T: bytemuck::Pod>(&mut self, offset: u8) -> Result<T, Error<E>> {
+        let mut value = T::zeroed();
+        let bytes = bytemuck::bytes_of_mut(&mut value);
+        self.validate_nvram_bounds(offset, bytes.len())?;
+
+        self.read_nvram(offset, bytes)?;
+
+        Ok(value)
+    }
+}
+
+/// Convert an `embedded-storage` offset into an NVRAM offset.
+///
+/// Offsets that do not fit in a `u8` are necessarily past the end of NVRAM;
+/// the error then reports the offset saturated to `u8::MAX`.
+#[cfg(feature = "embedded-storage")]
+fn storage_offset<E>(offset: u32, len: usize) -> Result<u8, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    u8::try_from(offset).map_err(|_| Error::NvramOutOfBounds {
+        offset: u8::MAX,
+        len,
+        capacity: NVRAM_SIZE,
+    })
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<I2C> embedded_storage::ReadStorage for Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    type Error = Error<I2C::Error>;
+
+    /// Read from NVRAM, see [`RtcNvram::read_nvram`].
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let offset = storage_offset(offset, bytes.len())?;
+        self.read_nvram(offset, bytes)
+    }
+
     /// Return the size of DS1307 NVRAM in bytes (56).
-    fn nvram_size(&self) -> u16 {
-        NVRAM_SIZE as u16
+    fn capacity(&self) -> usize {
+        NVRAM_SIZE as usize
+    }
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<I2C> embedded_storage::Storage for Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c,
+{
+    /// Write to NVRAM, see [`RtcNvram::write_nvram`].
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        let offset = storage_offset(offset, bytes.len())?;
+        self.write_nvram(offset, bytes)
     }
 }
 
@@ -85,6 +467,16 @@ mod tests {
         i2c_mock.done();
     }
 
+    #[test]
+    fn test_public_nvram_geometry() {
+        assert_eq!(crate::NVRAM_START, NVRAM_START);
+        assert_eq!(crate::NVRAM_SIZE, NVRAM_SIZE);
+        assert_eq!(
+            crate::NVRAM_START as usize + crate::NVRAM_SIZE as usize,
+            0x40
+        );
+    }
+
     #[test]
     fn test_validate_nvram_bounds_valid() {
         let i2c_mock = I2cMock::new(&[]);
@@ -108,11 +500,19 @@ mod tests {
         // Test invalid offset
         assert!(matches!(
             ds1307.validate_nvram_bounds(56, 1),
-            Err(Error::NvramOutOfBounds)
+            Err(Error::NvramOutOfBounds {
+                offset: 56,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
         ));
         assert!(matches!(
             ds1307.validate_nvram_bounds(100, 1),
-            Err(Error::NvramOutOfBounds)
+            Err(Error::NvramOutOfBounds {
+                offset: 100,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
         ));
 
         let mut i2c_mock = ds1307.release_i2c();
@@ -127,15 +527,27 @@ mod tests {
         // Test length that goes beyond NVRAM
         assert!(matches!(
             ds1307.validate_nvram_bounds(0, 57),
-            Err(Error::NvramOutOfBounds)
+            Err(Error::NvramOutOfBounds {
+                offset: 0,
+                len: 57,
+                capacity: NVRAM_SIZE
+            })
         ));
         assert!(matches!(
             ds1307.validate_nvram_bounds(55, 2),
-            Err(Error::NvramOutOfBounds)
+            Err(Error::NvramOutOfBounds {
+                offset: 55,
+                len: 2,
+                capacity: NVRAM_SIZE
+            })
         ));
         assert!(matches!(
             ds1307.validate_nvram_bounds(10, 50),
-            Err(Error::NvramOutOfBounds)
+            Err(Error::NvramOutOfBounds {
+                offset: 10,
+                len: 50,
+                capacity: NVRAM_SIZE
+            })
         ));
 
         let mut i2c_mock = ds1307.release_i2c();
@@ -162,6 +574,62 @@ mod tests {
         i2c_mock.done();
     }
 
+    #[test]
+    fn test_read_nvram_clamped_within_bounds() {
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![NVRAM_START + 5],
+            vec![0x01, 0x02, 0x03],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut buffer = [0u8; 3];
+        assert_eq!(ds1307.read_nvram_clamped(5, &mut buffer).unwrap(), 3);
+        assert_eq!(buffer, [0x01, 0x02, 0x03]);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_read_nvram_clamped_truncates_at_end() {
+        // Offset 53 leaves 3 bytes of NVRAM (0x3D-0x3F)
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![NVRAM_START + 53],
+            vec![0xAA, 0xBB, 0xCC],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut buffer = [0u8; 8];
+        assert_eq!(ds1307.read_nvram_clamped(53, &mut buffer).unwrap(), 3);
+        assert_eq!(buffer, [0xAA, 0xBB, 0xCC, 0, 0, 0, 0, 0]);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_read_nvram_clamped_out_of_range() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut buffer = [0u8; 4];
+        assert_eq!(
+            ds1307.read_nvram_clamped(NVRAM_SIZE, &mut buffer).unwrap(),
+            0
+        );
+        assert_eq!(ds1307.read_nvram_clamped(255, &mut buffer).unwrap(), 0);
+        assert_eq!(ds1307.read_nvram_clamped(0, &mut []).unwrap(), 0);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
     #[test]
     fn test_read_nvram_multiple_bytes() {
         let expectations = vec![I2cTransaction::write_read(
@@ -268,7 +736,14 @@ mod tests {
 
         let mut buffer = [0u8; 1];
         let result = ds1307.read_nvram(56, &mut buffer);
-        assert!(matches!(result, Err(Error::NvramOutOfBounds)));
+        assert!(matches!(
+            result,
+            Err(Error::NvramOutOfBounds {
+                offset: 56,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
 
         let mut i2c_mock = ds1307.release_i2c();
         i2c_mock.done();
@@ -281,7 +756,14 @@ mod tests {
 
         let mut buffer = [0u8; 2];
         let result = ds1307.read_nvram(55, &mut buffer);
-        assert!(matches!(result, Err(Error::NvramOutOfBounds)));
+        assert!(matches!(
+            result,
+            Err(Error::NvramOutOfBounds {
+                offset: 55,
+                len: 2,
+                capacity: NVRAM_SIZE
+            })
+        ));
 
         let mut i2c_mock = ds1307.release_i2c();
         i2c_mock.done();
@@ -415,7 +897,66 @@ mod tests {
 
         let data = [0x42];
         let result = ds1307.write_nvram(56, &data);
-        assert!(matches!(result, Err(Error::NvramOutOfBounds)));
+        assert!(matches!(
+            result,
+            Err(Error::NvramOutOfBounds {
+                offset: 56,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_nvram_offset_near_u8_boundary_rejected() {
+        // NVRAM_START + 255 would wrap to 0x07 (control register) in u8 arithmetic
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(matches!(
+            ds1307.validate_nvram_bounds(255, 1),
+            Err(Error::NvramOutOfBounds {
+                offset: 255,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(matches!(
+            ds1307.validate_nvram_bounds(0, usize::MAX),
+            Err(Error::NvramOutOfBounds {
+                offset: 0,
+                len: usize::MAX,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(matches!(
+            ds1307.write_nvram(255, &[0x42]),
+            Err(Error::NvramOutOfBounds {
+                offset: 255,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(matches!(
+            ds1307.write_nvram(248, &[0x42]),
+            Err(Error::NvramOutOfBounds {
+                offset: 248,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        let mut buffer = [0u8; 1];
+        assert!(matches!(
+            ds1307.read_nvram(255, &mut buffer),
+            Err(Error::NvramOutOfBounds {
+                offset: 255,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
 
         let mut i2c_mock = ds1307.release_i2c();
         i2c_mock.done();
@@ -428,7 +969,14 @@ mod tests {
 
         let data = [0x42, 0x43];
         let result = ds1307.write_nvram(55, &data);
-        assert!(matches!(result, Err(Error::NvramOutOfBounds)));
+        assert!(matches!(
+            result,
+            Err(Error::NvramOutOfBounds {
+                offset: 55,
+                len: 2,
+                capacity: NVRAM_SIZE
+            })
+        ));
 
         let mut i2c_mock = ds1307.release_i2c();
         i2c_mock.done();
@@ -537,4 +1085,779 @@ mod tests {
         let mut i2c_mock = ds1307.release_i2c();
         i2c_mock.done();
     }
+
+    #[test]
+    fn test_clear_nvram() {
+        let mut expected = vec![NVRAM_START];
+        expected.extend_from_slice(&[0x00; NVRAM_SIZE as usize]);
+        let expectations = vec![I2cTransaction::write(DS1307_ADDR, expected)];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.clear_nvram().is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_fill_nvram() {
+        let mut expected = vec![NVRAM_START];
+        expected.extend_from_slice(&[0xA5; NVRAM_SIZE as usize]);
+        let expectations = vec![I2cTransaction::write(DS1307_ADDR, expected)];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.fill_nvram(0xA5).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_fill_nvram_i2c_error() {
+        let mut expected = vec![NVRAM_START];
+        expected.extend_from_slice(&[0xFF; NVRAM_SIZE as usize]);
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, expected)
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.fill_nvram(0xFF).is_err());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_write_nvram_value() {
+        let value: u32 = 0x1234_5678;
+        let mut expected = vec![NVRAM_START + 4];
+        expected.extend_from_slice(&value.to_ne_bytes());
+        let expectations = vec![I2cTransaction::write(DS1307_ADDR, expected)];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.write_nvram_value(4, &value).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_read_nvram_value() {
+        let value: [u16; 3] = [0x0102, 0x0304, 0x0506];
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![NVRAM_START + 50],
+            bytemuck::bytes_of(&value).to_vec(),
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let result: [u16; 3] = ds1307.read_nvram_value(50).unwrap();
+        assert_eq!(result, value);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_nvram_value_out_of_bounds() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        // 8 bytes starting at offset 49 would end past the last NVRAM byte
+        assert!(matches!(
+            ds1307.write_nvram_value(49, &0u64),
+            Err(Error::NvramOutOfBounds {
+                offset: 49,
+                len: 8,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(matches!(
+            ds1307.read_nvram_value::<u64>(49),
+            Err(Error::NvramOutOfBounds {
+                offset: 49,
+                len: 8,
+                capacity: NVRAM_SIZE
+            })
+        ));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_crc8() {
+        assert_eq!(crc8(&[]), 0x00);
+        // CRC-8/SMBUS check value
+        assert_eq!(crc8(b"123456789"), 0xF4);
+    }
+
+    #[test]
+    fn test_write_nvram_checked() {
+        let expectations = vec![I2cTransaction::write(
+            DS1307_ADDR,
+            vec![
+                NVRAM_START + 2,
+                b'1',
+                b'2',
+                b'3',
+                b'4',
+                b'5',
+                b'6',
+                b'7',
+                b'8',
+                b'9',
+                0xF4,
+            ],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.write_nvram_checked(2, b"123456789").is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_read_nvram_checked() {
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![NVRAM_START + 2],
+            vec![b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', 0xF4],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut buffer = [0u8; 9];
+        assert!(ds1307.read_nvram_checked(2, &mut buffer).is_ok());
+        assert_eq!(&buffer, b"123456789");
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_read_nvram_checked_mismatch() {
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![NVRAM_START],
+            vec![0x01, 0x02, 0x00],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut buffer = [0u8; 2];
+        assert!(matches!(
+            ds1307.read_nvram_checked(0, &mut buffer),
+            Err(Error::NvramChecksum)
+        ));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_nvram_checked_out_of_bounds() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        // 56 data bytes leave no room for the checksum byte
+        let data = [0u8; NVRAM_SIZE as usize];
+        assert!(matches!(
+            ds1307.write_nvram_checked(0, &data),
+            Err(Error::NvramOutOfBounds {
+                offset: 0,
+                len: 57,
+                capacity: NVRAM_SIZE
+            })
+        ));
+
+        let mut buffer = [0u8; 1];
+        assert!(matches!(
+            ds1307.read_nvram_checked(55, &mut buffer),
+            Err(Error::NvramOutOfBounds {
+                offset: 55,
+                len: 2,
+                capacity: NVRAM_SIZE
+            })
+        ));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[cfg(feature = "embedded-storage")]
+    #[test]
+    fn test_storage_read_write() {
+        use embedded_storage::{ReadStorage, Storage};
+
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START + 3, 0xDE, 0xAD]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START + 3], vec![0xDE, 0xAD]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ReadStorage::capacity(&ds1307), NVRAM_SIZE as usize);
+        assert!(Storage::write(&mut ds1307, 3, &[0xDE, 0xAD]).is_ok());
+
+        let mut buffer = [0u8; 2];
+        assert!(ReadStorage::read(&mut ds1307, 3, &mut buffer).is_ok());
+        assert_eq!(buffer, [0xDE, 0xAD]);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[cfg(feature = "embedded-storage")]
+    #[test]
+    fn test_storage_out_of_bounds() {
+        use embedded_storage::{ReadStorage, Storage};
+
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut buffer = [0u8; 1];
+        assert!(matches!(
+            ReadStorage::read(&mut ds1307, 56, &mut buffer),
+            Err(Error::NvramOutOfBounds {
+                offset: 56,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(matches!(
+            ReadStorage::read(&mut ds1307, 0x1_0000, &mut buffer),
+            Err(Error::NvramOutOfBounds {
+                offset: 255,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(matches!(
+            Storage::write(&mut ds1307, 256, &[0x00]),
+            Err(Error::NvramOutOfBounds {
+                offset: 255,
+                len: 1,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(matches!(
+            Storage::write(&mut ds1307, 50, &[0x00; 7]),
+            Err(Error::NvramOutOfBounds {
+                offset: 50,
+                len: 7,
+                capacity: NVRAM_SIZE
+            })
+        ));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_mark_initialized_default_offset() {
+        let expectations = vec![I2cTransaction::write(
+            DS1307_ADDR,
+            vec![NVRAM_START + 52, 0xD5, 0x13, 0x07, 0xA5],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.power_marker_offset(), DEFAULT_POWER_MARKER_OFFSET);
+        ds1307.mark_initialized().unwrap();
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_mark_initialized_custom_offset() {
+        let expectations = vec![I2cTransaction::write(
+            DS1307_ADDR,
+            vec![NVRAM_START, 0xD5, 0x13, 0x07, 0xA5],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        ds1307.set_power_marker_offset(0).unwrap();
+        ds1307.mark_initialized().unwrap();
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_set_power_marker_offset_out_of_bounds() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(
+            ds1307.set_power_marker_offset(53),
+            Err(Error::NvramOutOfBounds {
+                offset: 53,
+                len: 4,
+                capacity: NVRAM_SIZE
+            })
+        );
+        assert_eq!(ds1307.power_marker_offset(), DEFAULT_POWER_MARKER_OFFSET);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_was_power_lost_marker_intact() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![0x00], vec![0x12]),
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![NVRAM_START + 52],
+                vec![0xD5, 0x13, 0x07, 0xA5],
+            ),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(!ds1307.was_power_lost().unwrap());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_was_power_lost_marker_missing() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![0x00], vec![0x12]),
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![NVRAM_START + 52],
+                vec![0x00, 0x00, 0x00, 0x00],
+            ),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.was_power_lost().unwrap());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_was_power_lost_clock_halted() {
+        // CH bit set: marker is not read
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![0x00],
+            vec![0x80],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.was_power_lost().unwrap());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_dump_nvram() {
+        let contents: Vec<u8> = (0..NVRAM_SIZE).collect();
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![NVRAM_START],
+            contents.clone(),
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.dump_nvram().unwrap().to_vec(), contents);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_write_nvram_in() {
+        let expectations = vec![I2cTransaction::write(
+            DS1307_ADDR,
+            vec![NVRAM_START + 3, 0xAA, 0xBB],
+        )];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut scratch = [0u8; 4];
+        assert!(
+            ds1307
+                .write_nvram_in(3, &[0xAA, 0xBB], &mut scratch)
+                .is_ok()
+        );
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_write_nvram_in_errors() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        // Scratch must hold the address byte as well
+        let mut scratch = [0u8; 2];
+        assert!(matches!(
+            ds1307.write_nvram_in(0, &[0xAA, 0xBB], &mut scratch),
+            Err(Error::BufferTooSmall)
+        ));
+        assert!(matches!(
+            ds1307.write_nvram_in(55, &[0xAA, 0xBB], &mut [0u8; 8]),
+            Err(Error::NvramOutOfBounds {
+                offset: 55,
+                len: 2,
+                capacity: NVRAM_SIZE
+            })
+        ));
+        assert!(ds1307.write_nvram_in(0, &[], &mut []).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_max_transfer_default_and_range() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.max_transfer(), MAX_NVRAM_WRITE);
+        assert_eq!(ds1307.set_max_transfer(1), Err(Error::InvalidTransferSize));
+        assert_eq!(ds1307.set_max_transfer(58), Err(Error::InvalidTransferSize));
+        assert_eq!(ds1307.max_transfer(), MAX_NVRAM_WRITE);
+        ds1307.set_max_transfer(2).unwrap();
+        assert_eq!(ds1307.max_transfer(), 2);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_new_with_max_transfer() {
+        let ds1307 = Ds1307::new_with_max_transfer(I2cMock::new(&[]), 16).unwrap();
+        assert_eq!(ds1307.max_transfer(), 16);
+        ds1307.release_i2c().done();
+
+        let bus = LimitedBus {
+            memory: [0; 64],
+            limit: MAX_NVRAM_WRITE,
+            reads: 0,
+        };
+        assert!(matches!(
+            Ds1307::new_with_max_transfer(bus, 0),
+            Err(Error::InvalidTransferSize)
+        ));
+    }
+
+    #[test]
+    fn test_write_nvram_chunked() {
+        // 5-byte transfers: address + 4 data bytes
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START + 2, 1, 2, 3, 4]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START + 6, 5, 6, 7, 8]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START + 10, 9, 10]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+        ds1307.set_max_transfer(5).unwrap();
+
+        assert!(
+            ds1307
+                .write_nvram(2, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+                .is_ok()
+        );
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_read_nvram_chunked() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START], vec![1, 2, 3, 4]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START + 4], vec![5, 6, 7, 8]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START + 8], vec![9, 10]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+        ds1307.set_max_transfer(4).unwrap();
+
+        let mut buffer = [0u8; 10];
+        assert!(ds1307.read_nvram(0, &mut buffer).is_ok());
+        assert_eq!(buffer, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    /// Register file behind a controller that rejects reads longer than `limit` bytes
+    struct LimitedBus {
+        memory: [u8; 64],
+        limit: usize,
+        reads: usize,
+    }
+
+    impl embedded_hal::i2c::ErrorType for LimitedBus {
+        type Error = embedded_hal::i2c::ErrorKind;
+    }
+
+    impl embedded_hal::i2c::I2c for LimitedBus {
+        fn transaction(
+            &mut self,
+            _address: u8,
+            operations: &mut [embedded_hal::i2c::Operation<'_>],
+        ) -> Result<(), Self::Error> {
+            let mut pointer = 0usize;
+            for operation in operations {
+                match operation {
+                    embedded_hal::i2c::Operation::Write(bytes) => pointer = bytes[0] as usize,
+                    embedded_hal::i2c::Operation::Read(buffer) => {
+                        if buffer.len() > self.limit {
+                            return Err(embedded_hal::i2c::ErrorKind::Overrun);
+                        }
+                        buffer.copy_from_slice(&self.memory[pointer..pointer + buffer.len()]);
+                        pointer += buffer.len();
+                        self.reads += 1;
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_full_nvram_with_limited_controller() {
+        let mut memory = [0u8; 64];
+        for (i, byte) in memory.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        let bus = LimitedBus {
+            memory,
+            limit: 16,
+            reads: 0,
+        };
+        let mut ds1307 = Ds1307::new(bus);
+        ds1307.set_max_transfer(16).unwrap();
+
+        let mut buffer = [0u8; NVRAM_SIZE as usize];
+        ds1307.read_nvram(0, &mut buffer).unwrap();
+
+        let bus = ds1307.release_i2c();
+        assert_eq!(buffer[..], bus.memory[NVRAM_START as usize..]);
+        assert_eq!(bus.reads, 4);
+    }
+
+    #[test]
+    fn test_fill_nvram_chunked() {
+        let mut first = vec![NVRAM_START];
+        first.extend_from_slice(&[0xFF; 28]);
+        let mut second = vec![NVRAM_START + 28];
+        second.extend_from_slice(&[0xFF; 28]);
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, first),
+            I2cTransaction::write(DS1307_ADDR, second),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+        ds1307.set_max_transfer(29).unwrap();
+
+        assert!(ds1307.fill_nvram(0xFF).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_write_nvram_in_chunked_small_scratch() {
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START, 1, 2]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START + 2, 3]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+        ds1307.set_max_transfer(3).unwrap();
+
+        let mut scratch = [0u8; 3];
+        assert!(ds1307.write_nvram_in(0, &[1, 2, 3], &mut scratch).is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_swap_nvram() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START + 10], vec![0x01, 0x02]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START + 10, 0x03, 0x04]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut old = [0u8; 2];
+        ds1307.swap_nvram(10, &[0x03, 0x04], &mut old).unwrap();
+        assert_eq!(old, [0x01, 0x02]);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_swap_nvram_rejects_bad_arguments() {
+        let i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut old = [0u8; 1];
+        assert_eq!(
+            ds1307.swap_nvram(0, &[0x01, 0x02], &mut old),
+            Err(Error::BufferTooSmall)
+        );
+
+        let mut old = [0u8; 2];
+        assert_eq!(
+            ds1307.swap_nvram(55, &[0x01, 0x02], &mut old),
+            Err(Error::NvramOutOfBounds {
+                offset: 55,
+                len: 2,
+                capacity: NVRAM_SIZE
+            })
+        );
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_self_test_passes() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START], vec![0x3C]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START, 0xC3]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START], vec![0xC3]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START, 0x3C]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert!(ds1307.self_test().is_ok());
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_self_test_stuck_device() {
+        // Reads always return 0x00: the pattern 0xFF never sticks
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START], vec![0x00]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START, 0xFF]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START], vec![0x00]),
+            I2cTransaction::write(DS1307_ADDR, vec![NVRAM_START, 0x00]),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        assert_eq!(ds1307.self_test(), Err(Error::SelfTestFailed));
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_write_and_read_nvram_all() {
+        let mut data = [0u8; NVRAM_SIZE as usize];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = i as u8 * 3;
+        }
+        let mut write = vec![NVRAM_START];
+        write.extend_from_slice(&data);
+        let expectations = vec![
+            I2cTransaction::write(DS1307_ADDR, write),
+            I2cTransaction::write_read(DS1307_ADDR, vec![NVRAM_START], data.to_vec()),
+        ];
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        ds1307.write_nvram_all(&data).unwrap();
+        assert_eq!(ds1307.read_nvram_all().unwrap(), data);
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_for_each_page() {
+        let mut expectations = Vec::new();
+        for offset in (0..48).step_by(16) {
+            expectations.push(I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![NVRAM_START + offset],
+                vec![offset; 16],
+            ));
+        }
+        expectations.push(I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![NVRAM_START + 48],
+            vec![48; 8],
+        ));
+
+        let i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(i2c_mock);
+
+        let mut pages = Vec::new();
+        ds1307
+            .for_each_page(16, |offset, bytes| {
+                assert!(bytes.iter().all(|&b| b == offset));
+                pages.push((offset, bytes.len()));
+            })
+            .unwrap();
+        assert_eq!(pages, [(0, 16), (16, 16), (32, 16), (48, 8)]);
+
+        assert_eq!(
+            ds1307.for_each_page(0, |_, _| {}),
+            Err(Error::InvalidPageSize)
+        );
+
+        let mut i2c_mock = ds1307.release_i2c();
+        i2c_mock.done();
+    }
 }
@@ -3,6 +3,7 @@
 /// DS1307 Registers
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Register {
     /// Seconds register (0x00) - BCD format 00-59, bit 7 = Clock Halt
     Seconds = 0x00,
@@ -32,6 +33,12 @@ impl Register {
 /// Seconds register (0x00) bit flags
 pub const CH_BIT: u8 = 0b1000_0000; // Clock Halt
 
+/// Hours register (0x02) bit flags
+///  12/24 hour mode select (1 = 12-hour mode)
+pub const HOUR_12_BIT: u8 = 0b0100_0000;
+/// AM/PM flag in 12-hour mode (1 = PM)
+pub const PM_BIT: u8 = 0b0010_0000;
+
 /// Control register (0x07) bit flags
 ///  Square Wave Enable
 pub const SQWE_BIT: u8 = 0b0001_0000;
@@ -41,8 +48,12 @@ pub const OUT_BIT: u8 = 0b1000_0000;
 pub const RS_MASK: u8 = 0b0000_0011;
 
 /// DS1307 NVRAM starts at register 0x08
+///
+/// NVRAM offsets passed to the driver are relative to this address.
 pub const NVRAM_START: u8 = 0x08;
 /// DS1307 has 56 bytes of NVRAM (0x08-0x3F)
+///
+/// Valid NVRAM offsets are `0..NVRAM_SIZE`.
 pub const NVRAM_SIZE: u8 = 56;
 /// 56 NVRAM + 1 address byte
 pub const MAX_NVRAM_WRITE: usize = 57;
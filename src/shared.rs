@@ -0,0 +1,114 @@
+//! Shared-reference access to the DS1307 driver.
+//!
+//! Every driver method takes `&mut self`, because [`embedded_hal::i2c::I2c`]
+//! requires exclusive access to the bus. [`SharedDs1307`] wraps the driver in
+//! a [`RefCell`] so reads can be issued through a plain `&` reference, e.g. when
+//! the driver lives in a struct that is itself shared by several consumers.
+
+use core::cell::RefCell;
+
+use rtc_hal::{nvram::RtcNvram, rtc::Rtc};
+
+use crate::{Ds1307, error::Error};
+
+/// DS1307 driver behind interior mutability
+///
+/// Each method borrows the inner driver only for the duration of the call.
+/// The wrapper is not [`Sync`]; for sharing between interrupt and thread
+/// context, put the driver behind a critical-section mutex instead.
+pub struct SharedDs1307<I2C> {
+    inner: RefCell<Ds1307<I2C>>,
+}
+
+impl<I2C, E> SharedDs1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Wrap an existing driver.
+    pub fn new(driver: Ds1307<I2C>) -> Self {
+        Self {
+            inner: RefCell::new(driver),
+        }
+    }
+
+    /// Return the inner driver.
+    pub fn into_inner(self) -> Ds1307<I2C> {
+        self.inner.into_inner()
+    }
+
+    /// Run `f` with exclusive access to the inner driver.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called re-entrantly from within `f`.
+    pub fn with<R>(&self, f: impl FnOnce(&mut Ds1307<I2C>) -> R) -> R {
+        f(&mut self.inner.borrow_mut())
+    }
+
+    /// Read the current date and time, see [`Rtc::get_datetime`].
+    pub fn get_datetime(&self) -> Result<rtc_hal::datetime::DateTime, Error<E>> {
+        self.with(|rtc| rtc.get_datetime())
+    }
+
+    /// Read NVRAM starting at `offset`, see [`RtcNvram::read_nvram`].
+    pub fn read_nvram(&self, offset: u8, buffer: &mut [u8]) -> Result<(), Error<E>> {
+        self.with(|rtc| rtc.read_nvram(offset, buffer))
+    }
+
+    /// Read the control register, see [`Ds1307::read_control`].
+    pub fn read_control(&self) -> Result<u8, Error<E>> {
+        self.with(|rtc| rtc.read_control())
+    }
+
+    /// Read all eight timekeeping and control registers, see
+    /// [`Ds1307::dump_registers`].
+    pub fn dump_registers(&self) -> Result<[u8; 8], Error<E>> {
+        self.with(|rtc| rtc.dump_registers())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+
+    const DS1307_ADDR: u8 = 0x68;
+
+    #[test]
+    fn test_reads_through_shared_reference() {
+        let expectations = [
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![0x00],
+                vec![0x30, 0x15, 0x10, 0x03, 0x31, 0x12, 0x24],
+            ),
+            I2cTransaction::write_read(DS1307_ADDR, vec![0x08], vec![0xAA, 0xBB]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![0x07], vec![0x10]),
+        ];
+        let shared = SharedDs1307::new(Ds1307::new(I2cMock::new(&expectations)));
+        let rtc = &shared;
+
+        let dt = rtc.get_datetime().unwrap();
+        assert_eq!((dt.year(), dt.month(), dt.day_of_month()), (2024, 12, 31));
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (10, 15, 30));
+
+        let mut buf = [0u8; 2];
+        rtc.read_nvram(0, &mut buf).unwrap();
+        assert_eq!(buf, [0xAA, 0xBB]);
+
+        assert_eq!(rtc.read_control().unwrap(), 0x10);
+
+        shared.into_inner().release_i2c().done();
+    }
+
+    #[test]
+    fn test_with_gives_mutable_access() {
+        let expectations = [I2cTransaction::write(DS1307_ADDR, vec![0x07, 0x00])];
+        let shared = SharedDs1307::new(Ds1307::new(I2cMock::new(&expectations)));
+
+        shared.with(|rtc| rtc.reset_control()).unwrap();
+
+        shared.into_inner().release_i2c().done();
+    }
+}
@@ -14,26 +14,116 @@ pub use rtc_hal::square_wave::SquareWave;
 pub use rtc_hal::square_wave::SquareWaveFreq;
 
 use crate::Ds1307;
+use crate::ds1307::WriteOutcome;
 use crate::error::Error;
 use crate::registers::Register;
 use crate::registers::{OUT_BIT, RS_MASK, SQWE_BIT};
 
+/// Convert a [`SquareWaveFreq`] into the corresponding DS1307 RS bits, in a
+/// `const` context.
+///
+/// Returns `None` if the frequency is not supported by the DS1307. Combined
+/// with [`SQWE_BIT`], this builds a control register value at compile time:
+///
+/// ```
+/// use ds1307_rtc::registers::SQWE_BIT;
+/// use ds1307_rtc::square_wave::{SquareWaveFreq, freq_to_rs_bits};
+///
+/// const CONTROL: u8 = SQWE_BIT | freq_to_rs_bits(SquareWaveFreq::Hz4096).unwrap();
+/// assert_eq!(CONTROL, 0x11);
+/// ```
+pub const fn freq_to_rs_bits(freq: SquareWaveFreq) -> Option<u8> {
+    match freq {
+        SquareWaveFreq::Hz1 => Some(0b0000_0000),
+        SquareWaveFreq::Hz4096 => Some(0b0000_0001),
+        SquareWaveFreq::Hz8192 => Some(0b0000_0010),
+        SquareWaveFreq::Hz32768 => Some(0b0000_0011),
+        _ => None,
+    }
+}
+
 /// Convert a [`SquareWaveFreq`] into the corresponding DS1307 RS bits.
 ///
 /// Returns an error if the frequency is not supported by the DS1307.
-fn freq_to_bits<E>(freq: SquareWaveFreq) -> Result<u8, Error<E>>
+pub(crate) fn freq_to_bits<E>(freq: SquareWaveFreq) -> Result<u8, Error<E>>
 where
     E: core::fmt::Debug,
 {
-    match freq {
-        SquareWaveFreq::Hz1 => Ok(0b0000_0000),
-        SquareWaveFreq::Hz4096 => Ok(0b0000_0001),
-        SquareWaveFreq::Hz8192 => Ok(0b0000_0010),
-        SquareWaveFreq::Hz32768 => Ok(0b0000_0011),
-        _ => Err(Error::UnsupportedSqwFrequency),
+    freq_to_rs_bits(freq).ok_or(Error::UnsupportedSqwFrequency)
+}
+
+/// Period of the DS1307 square wave output at `freq`, in microseconds.
+///
+/// Values are rounded to the nearest microsecond: 1 Hz gives 1 000 000 µs,
+/// 4.096 kHz 244 µs, 8.192 kHz 122 µs and 32.768 kHz 31 µs. Returns `None`
+/// for frequencies the DS1307 cannot output.
+pub const fn square_wave_period_us(freq: SquareWaveFreq) -> Option<u32> {
+    let hz: u32 = match freq {
+        SquareWaveFreq::Hz1 => 1,
+        SquareWaveFreq::Hz4096 => 4096,
+        SquareWaveFreq::Hz8192 => 8192,
+        SquareWaveFreq::Hz32768 => 32768,
+        _ => return None,
+    };
+
+    Some((1_000_000 + hz / 2) / hz)
+}
+
+/// Frequency selected by the RS bits of a control register value.
+fn bits_to_freq(control: u8) -> SquareWaveFreq {
+    match control & RS_MASK {
+        0b00 => SquareWaveFreq::Hz1,
+        0b01 => SquareWaveFreq::Hz4096,
+        0b10 => SquareWaveFreq::Hz8192,
+        _ => SquareWaveFreq::Hz32768,
     }
 }
 
+/// Decoded SQW/OUT configuration of the control register
+///
+/// Read with [`Ds1307::get_square_wave_config`] and written with
+/// [`Ds1307::set_square_wave_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SquareWaveConfig {
+    /// Square wave output enabled (SQWE bit)
+    pub enabled: bool,
+    /// Square wave frequency (RS bits), also kept while the output is disabled
+    #[cfg_attr(feature = "defmt", defmt(Debug2Format))]
+    pub freq: SquareWaveFreq,
+    /// Static pin level while the square wave is disabled (OUT bit)
+    pub out_level: bool,
+}
+
+/// Saved SQW/OUT configuration (the SQWE, RS and OUT bits of the control register)
+///
+/// Obtained from [`Ds1307::snapshot_square_wave`] and applied again with
+/// [`Ds1307::restore_square_wave`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SquareWaveState {
+    control: u8,
+}
+
+/// Control register bits captured by [`SquareWaveState`]
+const OUTPUT_MASK: u8 = OUT_BIT | SQWE_BIT | RS_MASK;
+
+/// Square wave frequencies supported by the DS1307, in ascending order.
+pub const SUPPORTED_FREQUENCIES: &[SquareWaveFreq] = &[
+    SquareWaveFreq::Hz1,
+    SquareWaveFreq::Hz4096,
+    SquareWaveFreq::Hz8192,
+    SquareWaveFreq::Hz32768,
+];
+
+/// Check whether the DS1307 can output the given square wave frequency.
+///
+/// Uses the same mapping as the driver itself, so user input can be validated
+/// up front without touching the bus. Usable in const contexts.
+pub const fn is_supported_frequency(freq: SquareWaveFreq) -> bool {
+    freq_to_rs_bits(freq).is_some()
+}
+
 impl<I2C> SquareWave for Ds1307<I2C>
 where
     I2C: embedded_hal::i2c::I2c,
@@ -70,12 +160,23 @@ where
 
     /// Enable the square wave output
     fn enable_square_wave(&mut self) -> Result<(), Self::Error> {
+        self.enable_square_wave_with_outcome().map(|_| ())
+    }
+
+    /// Disable the square wave output.
+    fn disable_square_wave(&mut self) -> Result<(), Self::Error> {
+        self.disable_square_wave_with_outcome().map(|_| ())
+    }
+
+    /// Change the square wave output frequency without enabling or disabling it.
+    fn set_square_wave_frequency(&mut self, freq: SquareWaveFreq) -> Result<(), Self::Error> {
+        let rs_bits = freq_to_bits(freq)?;
         let current = self.read_register(Register::Control)?;
         let mut new_value = current;
 
-        // Enable square wave, disable OUT
-        new_value |= SQWE_BIT;
-        new_value &= !OUT_BIT;
+        // Clear frequency bits and set new ones (preserve enable/disable state)
+        new_value &= !RS_MASK;
+        new_value |= rs_bits;
 
         // Only write if changed
         if new_value != current {
@@ -84,22 +185,142 @@ where
             Ok(())
         }
     }
+}
 
-    /// Disable the square wave output.
-    fn disable_square_wave(&mut self) -> Result<(), Self::Error> {
+impl<I2C, E> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Enable the square wave output, reporting whether the control register
+    /// was written.
+    ///
+    /// See [`SquareWave::enable_square_wave`].
+    pub fn enable_square_wave_with_outcome(&mut self) -> Result<WriteOutcome, Error<E>> {
+        // Enable square wave, disable OUT
+        self.update_register(Register::Control, |current| (current | SQWE_BIT) & !OUT_BIT)
+    }
+
+    /// Disable the square wave output, reporting whether the control register
+    /// was written.
+    ///
+    /// See [`SquareWave::disable_square_wave`].
+    pub fn disable_square_wave_with_outcome(&mut self) -> Result<WriteOutcome, Error<E>> {
         self.clear_register_bits(Register::Control, SQWE_BIT)
     }
 
-    /// Change the square wave output frequency without enabling or disabling it.
-    fn set_square_wave_frequency(&mut self, freq: SquareWaveFreq) -> Result<(), Self::Error> {
+    /// Check whether the square wave output is enabled (SQWE bit set).
+    pub fn is_square_wave_enabled(&mut self) -> Result<bool, Error<E>> {
+        let value = self.read_register(Register::Control)?;
+        Ok((value & SQWE_BIT) != 0)
+    }
+
+    /// Save the current SQW/OUT configuration (SQWE, RS and OUT bits).
+    pub fn snapshot_square_wave(&mut self) -> Result<SquareWaveState, Error<E>> {
+        let value = self.read_register(Register::Control)?;
+        Ok(SquareWaveState {
+            control: value & OUTPUT_MASK,
+        })
+    }
+
+    /// Restore a SQW/OUT configuration saved by [`snapshot_square_wave`](Self::snapshot_square_wave).
+    ///
+    /// Other control register bits are preserved, and the write is skipped if
+    /// nothing changes.
+    pub fn restore_square_wave(&mut self, state: SquareWaveState) -> Result<(), Error<E>> {
+        let current = self.read_register(Register::Control)?;
+        let new_value = (current & !OUTPUT_MASK) | state.control;
+
+        // Only write if changed
+        if new_value != current {
+            self.write_register(Register::Control, new_value)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Read the complete SQW/OUT configuration with a single control register read.
+    pub fn get_square_wave_config(&mut self) -> Result<SquareWaveConfig, Error<E>> {
+        let value = self.read_register(Register::Control)?;
+
+        Ok(SquareWaveConfig {
+            enabled: (value & SQWE_BIT) != 0,
+            freq: bits_to_freq(value),
+            out_level: (value & OUT_BIT) != 0,
+        })
+    }
+
+    /// Apply a complete SQW/OUT configuration.
+    ///
+    /// Unlike [`configure_square_wave`](Self::configure_square_wave), the OUT
+    /// bit is written exactly as given. Other control register bits are
+    /// preserved, and the write is skipped if nothing changes. Returns
+    /// [`Error::UnsupportedSqwFrequency`] before any bus access if
+    /// `config.freq` is not supported by the DS1307.
+    pub fn set_square_wave_config(&mut self, config: SquareWaveConfig) -> Result<(), Error<E>> {
+        let mut control = freq_to_bits(config.freq)?;
+        if config.enabled {
+            control |= SQWE_BIT;
+        }
+        if config.out_level {
+            control |= OUT_BIT;
+        }
+
+        self.update_register(Register::Control, |current| {
+            (current & !OUTPUT_MASK) | control
+        })
+        .map(|_| ())
+    }
+
+    /// Run `f` with the square wave output temporarily enabled at `freq`.
+    ///
+    /// The previous SQW/OUT configuration is saved first and restored after
+    /// `f` returns, e.g. to measure the 1 Hz output for crystal calibration.
+    /// If enabling the square wave fails, `f` is not called.
+    pub fn with_square_wave<F, R>(&mut self, freq: SquareWaveFreq, f: F) -> Result<R, Error<E>>
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        freq_to_bits::<E>(freq)?;
+        let state = self.snapshot_square_wave()?;
+
+        self.configure_square_wave(freq, true)?;
+        let result = f(self);
+        self.restore_square_wave(state)?;
+
+        Ok(result)
+    }
+
+    /// Set the square wave frequency and enable state in a single update.
+    ///
+    /// Computes the final control register value once, so at most one read and
+    /// one write are issued. The write is skipped if nothing changes. Enabling
+    /// also clears OUT, like [`SquareWave::start_square_wave`]; disabling leaves
+    /// OUT untouched, like [`SquareWave::disable_square_wave`].
+    ///
+    /// Returns [`Error::UnsupportedSqwFrequency`] before any bus access if `freq`
+    /// is not supported by the DS1307.
+    pub fn configure_square_wave(
+        &mut self,
+        freq: SquareWaveFreq,
+        enabled: bool,
+    ) -> Result<(), Error<E>> {
         let rs_bits = freq_to_bits(freq)?;
         let current = self.read_register(Register::Control)?;
         let mut new_value = current;
 
-        // Clear frequency bits and set new ones (preserve enable/disable state)
+        // Clear frequency bits and set new ones
         new_value &= !RS_MASK;
         new_value |= rs_bits;
 
+        if enabled {
+            // Enable square wave, disable OUT
+            new_value |= SQWE_BIT;
+            new_value &= !OUT_BIT;
+        } else {
+            new_value &= !SQWE_BIT;
+        }
+
         // Only write if changed
         if new_value != current {
             self.write_register(Register::Control, new_value)
@@ -123,6 +344,54 @@ mod tests {
         assert!(matches!(result, Err(Error::UnsupportedSqwFrequency)));
     }
 
+    #[test]
+    fn test_is_supported_frequency() {
+        assert!(is_supported_frequency(SquareWaveFreq::Hz1));
+        assert!(is_supported_frequency(SquareWaveFreq::Hz4096));
+        assert!(is_supported_frequency(SquareWaveFreq::Hz8192));
+        assert!(is_supported_frequency(SquareWaveFreq::Hz32768));
+        assert!(!is_supported_frequency(SquareWaveFreq::Hz1024));
+        assert!(!is_supported_frequency(SquareWaveFreq::Custom(1)));
+
+        const { assert!(is_supported_frequency(SUPPORTED_FREQUENCIES[3])) };
+    }
+
+    #[test]
+    fn test_freq_to_rs_bits_const() {
+        const RS: Option<u8> = freq_to_rs_bits(SquareWaveFreq::Hz32768);
+        assert_eq!(RS, Some(RS_MASK));
+        assert_eq!(freq_to_rs_bits(SquareWaveFreq::Hz1), Some(0b00));
+        assert_eq!(freq_to_rs_bits(SquareWaveFreq::Hz1024), None);
+    }
+
+    #[test]
+    fn test_square_wave_period_us() {
+        assert_eq!(square_wave_period_us(SquareWaveFreq::Hz1), Some(1_000_000));
+        assert_eq!(square_wave_period_us(SquareWaveFreq::Hz4096), Some(244));
+        assert_eq!(square_wave_period_us(SquareWaveFreq::Hz8192), Some(122));
+        assert_eq!(square_wave_period_us(SquareWaveFreq::Hz32768), Some(31));
+        assert_eq!(square_wave_period_us(SquareWaveFreq::Hz1024), None);
+        assert_eq!(square_wave_period_us(SquareWaveFreq::Custom(1)), None);
+
+        for &freq in SUPPORTED_FREQUENCIES {
+            assert!(square_wave_period_us(freq).is_some());
+        }
+    }
+
+    #[test]
+    fn test_supported_frequencies_match_freq_to_bits() {
+        for &freq in SUPPORTED_FREQUENCIES {
+            assert!(freq_to_bits::<()>(freq).is_ok());
+        }
+
+        // Each supported frequency maps to a distinct RS value
+        let bits: Vec<u8> = SUPPORTED_FREQUENCIES
+            .iter()
+            .map(|&freq| freq_to_bits::<()>(freq).unwrap())
+            .collect();
+        assert_eq!(bits, vec![0b00, 0b01, 0b10, 0b11]);
+    }
+
     #[test]
     fn test_enable_square_wave() {
         let expectations = vec![
@@ -558,4 +827,279 @@ mod tests {
 
         i2c_mock.done();
     }
+
+    #[test]
+    fn test_is_square_wave_enabled() {
+        let expectations = vec![
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Control.addr()],
+                vec![0b0001_0011],
+            ),
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Control.addr()],
+                vec![0b1000_0011],
+            ),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert!(ds1307.is_square_wave_enabled().unwrap());
+        assert!(!ds1307.is_square_wave_enabled().unwrap());
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_is_square_wave_enabled_read_error() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x00])
+                .with_error(embedded_hal::i2c::ErrorKind::Other),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert!(ds1307.is_square_wave_enabled().is_err());
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_square_wave_enable() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![OUT_BIT]),
+            I2cTransaction::write(
+                DS1307_ADDR,
+                vec![Register::Control.addr(), SQWE_BIT | 0b0000_0010],
+            ),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert!(
+            ds1307
+                .configure_square_wave(SquareWaveFreq::Hz8192, true)
+                .is_ok()
+        );
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_square_wave_disable_preserves_out() {
+        let expectations = vec![
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Control.addr()],
+                vec![OUT_BIT | SQWE_BIT | 0b0000_0011],
+            ),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), OUT_BIT]),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert!(
+            ds1307
+                .configure_square_wave(SquareWaveFreq::Hz1, false)
+                .is_ok()
+        );
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_square_wave_no_change() {
+        let expectations = vec![I2cTransaction::write_read(
+            DS1307_ADDR,
+            vec![Register::Control.addr()],
+            vec![SQWE_BIT | 0b0000_0001],
+        )];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert!(
+            ds1307
+                .configure_square_wave(SquareWaveFreq::Hz4096, true)
+                .is_ok()
+        );
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_configure_square_wave_unsupported_frequency() {
+        let mut i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert!(matches!(
+            ds1307.configure_square_wave(SquareWaveFreq::Hz1024, true),
+            Err(Error::UnsupportedSqwFrequency)
+        ));
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_snapshot_and_restore_square_wave() {
+        let expectations = vec![
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![OUT_BIT]),
+            // Changed to 1 Hz square wave in the meantime
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![SQWE_BIT]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), OUT_BIT]),
+            // Already restored: no write
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![OUT_BIT]),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        let state = ds1307.snapshot_square_wave().unwrap();
+        ds1307.restore_square_wave(state).unwrap();
+        ds1307.restore_square_wave(state).unwrap();
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_with_square_wave() {
+        let expectations = vec![
+            // Snapshot: 32.768 kHz disabled, OUT high
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Control.addr()],
+                vec![OUT_BIT | 0b0000_0011],
+            ),
+            // Enable 1 Hz
+            I2cTransaction::write_read(
+                DS1307_ADDR,
+                vec![Register::Control.addr()],
+                vec![OUT_BIT | 0b0000_0011],
+            ),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), SQWE_BIT]),
+            // Restore
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![SQWE_BIT]),
+            I2cTransaction::write(
+                DS1307_ADDR,
+                vec![Register::Control.addr(), OUT_BIT | 0b0000_0011],
+            ),
+        ];
+
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        let measured = ds1307
+            .with_square_wave(SquareWaveFreq::Hz1, |_| 42)
+            .unwrap();
+        assert_eq!(measured, 42);
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_with_square_wave_unsupported_frequency() {
+        let mut i2c_mock = I2cMock::new(&[]);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        let mut called = false;
+        assert!(matches!(
+            ds1307.with_square_wave(SquareWaveFreq::Hz1024, |_| called = true),
+            Err(Error::UnsupportedSqwFrequency)
+        ));
+        assert!(!called);
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_square_wave_with_outcome() {
+        let expectations = [
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x80]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x10]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x10]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x00]),
+        ];
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert_eq!(
+            ds1307.enable_square_wave_with_outcome().unwrap(),
+            WriteOutcome::Changed
+        );
+        assert_eq!(
+            ds1307.enable_square_wave_with_outcome().unwrap(),
+            WriteOutcome::Unchanged
+        );
+        assert_eq!(
+            ds1307.disable_square_wave_with_outcome().unwrap(),
+            WriteOutcome::Unchanged
+        );
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_get_square_wave_config() {
+        let expectations = [
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x12]),
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x83]),
+        ];
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        assert_eq!(
+            ds1307.get_square_wave_config().unwrap(),
+            SquareWaveConfig {
+                enabled: true,
+                freq: SquareWaveFreq::Hz8192,
+                out_level: false,
+            }
+        );
+        assert_eq!(
+            ds1307.get_square_wave_config().unwrap(),
+            SquareWaveConfig {
+                enabled: false,
+                freq: SquareWaveFreq::Hz32768,
+                out_level: true,
+            }
+        );
+
+        i2c_mock.done();
+    }
+
+    #[test]
+    fn test_set_square_wave_config() {
+        let config = SquareWaveConfig {
+            enabled: false,
+            freq: SquareWaveFreq::Hz4096,
+            out_level: true,
+        };
+        let expectations = [
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x10]),
+            I2cTransaction::write(DS1307_ADDR, vec![Register::Control.addr(), 0x81]),
+            // Already configured: no write
+            I2cTransaction::write_read(DS1307_ADDR, vec![Register::Control.addr()], vec![0x81]),
+        ];
+        let mut i2c_mock = I2cMock::new(&expectations);
+        let mut ds1307 = Ds1307::new(&mut i2c_mock);
+
+        ds1307.set_square_wave_config(config).unwrap();
+        ds1307.set_square_wave_config(config).unwrap();
+
+        let unsupported = SquareWaveConfig {
+            freq: SquareWaveFreq::Hz1024,
+            ..config
+        };
+        assert_eq!(
+            ds1307.set_square_wave_config(unsupported),
+            Err(Error::UnsupportedSqwFrequency)
+        );
+
+        i2c_mock.done();
+    }
 }
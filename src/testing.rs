@@ -0,0 +1,131 @@
+//! # Testing Helpers
+//!
+//! This module builds the I2C transactions the driver issues for its burst
+//! datetime accesses, so downstream tests using
+//! [`embedded-hal-mock`](https://crates.io/crates/embedded-hal-mock) can assert
+//! against the exact bus traffic without duplicating the register layout.
+//!
+//! All helpers target the default DS1307 address (0x68).
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+
+use embedded_hal_mock::eh1::i2c::Transaction;
+use rtc_hal::datetime::{DateTime, Weekday};
+
+use crate::{
+    datetime::{DEFAULT_CENTURY_BASE, HourFormat, encode_registers},
+    ds1307::I2C_ADDR,
+    registers::Register,
+};
+
+/// Encode `datetime` into the seven timekeeping registers (0x00-0x06).
+///
+/// # Panics
+///
+/// Panics if `datetime` is outside the DS1307 range (2000-2099).
+fn registers_for(datetime: &DateTime) -> [u8; 7] {
+    encode_registers(
+        datetime,
+        HourFormat::TwentyFourHour,
+        Weekday::Sunday,
+        DEFAULT_CENTURY_BASE,
+    )
+    .expect("datetime outside the DS1307 range (2000-2099)")
+}
+
+/// Expected transactions for [`Rtc::set_datetime`](rtc_hal::rtc::Rtc::set_datetime).
+///
+/// A single burst write of the seven timekeeping registers in 24-hour mode.
+///
+/// # Panics
+///
+/// Panics if `datetime` is outside the DS1307 range (2000-2099).
+pub fn set_datetime_transactions(datetime: &DateTime) -> Vec<Transaction> {
+    let mut data = vec![Register::Seconds.addr()];
+    data.extend_from_slice(&registers_for(datetime));
+
+    vec![Transaction::write(I2C_ADDR, data)]
+}
+
+/// Expected transactions for [`Rtc::get_datetime`](rtc_hal::rtc::Rtc::get_datetime),
+/// with the device returning `datetime`.
+///
+/// A single burst read of the seven timekeeping registers in 24-hour mode.
+///
+/// # Panics
+///
+/// Panics if `datetime` is outside the DS1307 range (2000-2099).
+pub fn get_datetime_transactions(datetime: &DateTime) -> Vec<Transaction> {
+    get_raw_datetime_transactions(registers_for(datetime))
+}
+
+/// Expected transactions for [`Rtc::get_datetime`](rtc_hal::rtc::Rtc::get_datetime),
+/// with the device returning the raw register values `registers` (0x00-0x06).
+///
+/// Useful for simulating a halted clock, 12-hour mode or corrupted registers.
+pub fn get_raw_datetime_transactions(registers: [u8; 7]) -> Vec<Transaction> {
+    vec![Transaction::write_read(
+        I2C_ADDR,
+        vec![Register::Seconds.addr()],
+        registers.to_vec(),
+    )]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ds1307, error::Error};
+    use embedded_hal_mock::eh1::i2c::Mock as I2cMock;
+    use rtc_hal::rtc::Rtc;
+
+    #[test]
+    fn test_set_datetime_transactions() {
+        let dt = DateTime::new(2024, 12, 31, 23, 59, 58).unwrap();
+        let expectations = set_datetime_transactions(&dt);
+        assert_eq!(
+            expectations,
+            vec![Transaction::write(
+                0x68,
+                vec![0x00, 0x58, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24]
+            )]
+        );
+
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        ds1307.set_datetime(&dt).unwrap();
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_datetime_transactions() {
+        let dt = DateTime::new(2023, 8, 15, 7, 5, 9).unwrap();
+        let expectations = get_datetime_transactions(&dt);
+
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        assert_eq!(ds1307.get_datetime().unwrap(), dt);
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_get_raw_datetime_transactions() {
+        let expectations =
+            get_raw_datetime_transactions([0x00, 0x00, 0x00, 0x01, 0x01, 0x13, 0x00]);
+
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        assert_eq!(
+            ds1307.get_datetime(),
+            Err(Error::InvalidBcd {
+                register: Register::Month
+            })
+        );
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_datetime_transactions_out_of_range() {
+        let dt = DateTime::new(2100, 1, 1, 0, 0, 0).unwrap();
+        set_datetime_transactions(&dt);
+    }
+}
@@ -0,0 +1,232 @@
+//! # time Interoperability
+//!
+//! Conversions between [`DateTime`] and [`time::PrimitiveDateTime`], plus
+//! [`Ds1307`] methods that read and set the clock with `time` types.
+//!
+//! The `time` crate is pulled in with default features disabled, so this
+//! works in `no_std`. `time` only represents years up to 9999, so even the
+//! conversion out of a [`DateTime`] is fallible.
+//!
+//! Enable with the `time` feature.
+
+use rtc_hal::{
+    datetime::{DateTime, DateTimeError},
+    rtc::Rtc,
+};
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+use crate::{
+    Ds1307,
+    datetime::{datetime_from_fields, validate_for_ds1307},
+    error::Error,
+};
+
+/// Convert a [`DateTime`] into a [`time::PrimitiveDateTime`].
+///
+/// Returns [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the year
+/// is outside the range supported by the `time` crate (only possible for
+/// years above 9999).
+pub fn to_primitive_datetime<E>(datetime: &DateTime) -> Result<PrimitiveDateTime, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    let month = Month::try_from(datetime.month())
+        .map_err(|_| Error::DateTime(DateTimeError::InvalidMonth))?;
+    let date = Date::from_calendar_date(datetime.year() as i32, month, datetime.day_of_month())
+        .map_err(|_| Error::DateTime(DateTimeError::InvalidYear))?;
+    let time = Time::from_hms(datetime.hour(), datetime.minute(), datetime.second())
+        .map_err(|_| Error::DateTime(DateTimeError::InvalidHour))?;
+
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+/// Convert a [`time::PrimitiveDateTime`] into a [`DateTime`] within the DS1307 range.
+///
+/// Returns [`Error::DateTime`] with [`DateTimeError::InvalidYear`] if the year
+/// is outside 2000-2099, including the negative years `time` can represent.
+/// Nanoseconds are discarded.
+///
+/// [`Ds1307::set_primitive_datetime`] checks the driver's own century window
+/// instead.
+pub fn from_primitive_datetime<E>(datetime: &PrimitiveDateTime) -> Result<DateTime, Error<E>>
+where
+    E: core::fmt::Debug,
+{
+    let datetime = primitive_to_datetime(datetime).map_err(Error::DateTime)?;
+    validate_for_ds1307(&datetime)?;
+
+    Ok(datetime)
+}
+
+/// [`from_primitive_datetime`] without the 2000-2099 check.
+fn primitive_to_datetime(datetime: &PrimitiveDateTime) -> Result<DateTime, DateTimeError> {
+    datetime_from_fields(
+        datetime.year(),
+        u8::from(datetime.month()),
+        datetime.day(),
+        datetime.hour(),
+        datetime.minute(),
+        datetime.second(),
+    )
+}
+
+impl<I2C, E> Ds1307<I2C>
+where
+    I2C: embedded_hal::i2c::I2c<Error = E>,
+    E: core::fmt::Debug,
+{
+    /// Read the current date and time as a [`time::PrimitiveDateTime`].
+    pub fn get_primitive_datetime(&mut self) -> Result<PrimitiveDateTime, Error<E>> {
+        let datetime = self.get_datetime()?;
+        to_primitive_datetime(&datetime)
+    }
+
+    /// Set the current date and time from a [`time::PrimitiveDateTime`],
+    /// discarding nanoseconds.
+    ///
+    /// The year must fall in the driver's century window, see
+    /// [`set_century_base`](Self::set_century_base); otherwise
+    /// [`Error::DateTime`] with [`DateTimeError::InvalidYear`] is returned and
+    /// nothing is written.
+    pub fn set_primitive_datetime(&mut self, datetime: &PrimitiveDateTime) -> Result<(), Error<E>> {
+        let datetime = primitive_to_datetime(datetime).map_err(Error::DateTime)?;
+        self.set_datetime(&datetime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::Register;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+    fn primitive(year: i32, month: u8, day: u8, hour: u8, min: u8, sec: u8) -> PrimitiveDateTime {
+        let date = Date::from_calendar_date(year, Month::try_from(month).unwrap(), day).unwrap();
+        PrimitiveDateTime::new(date, Time::from_hms(hour, min, sec).unwrap())
+    }
+
+    #[test]
+    fn test_to_primitive_datetime() {
+        let datetime = DateTime::new(2025, 8, 27, 15, 30, 45).unwrap();
+        assert_eq!(
+            to_primitive_datetime::<()>(&datetime).unwrap(),
+            primitive(2025, 8, 27, 15, 30, 45)
+        );
+    }
+
+    #[test]
+    fn test_to_primitive_datetime_beyond_year_9999() {
+        let datetime = DateTime::new(10_000, 1, 1, 0, 0, 0).unwrap();
+        assert!(matches!(
+            to_primitive_datetime::<()>(&datetime),
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+    }
+
+    #[test]
+    fn test_from_primitive_datetime_discards_nanoseconds() {
+        let input = primitive(2024, 2, 29, 23, 59, 59)
+            .replace_nanosecond(999_999_999)
+            .unwrap();
+        let datetime = from_primitive_datetime::<()>(&input).unwrap();
+        assert_eq!(datetime, DateTime::new(2024, 2, 29, 23, 59, 59).unwrap());
+    }
+
+    #[test]
+    fn test_from_primitive_datetime_out_of_range() {
+        // Negative years are valid in `time`
+        for year in [-1, 1969, 1999, 2100] {
+            let result = from_primitive_datetime::<()>(&primitive(year, 1, 1, 0, 0, 0));
+            assert!(matches!(
+                result,
+                Err(Error::DateTime(DateTimeError::InvalidYear))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_get_primitive_datetime() {
+        let data = [0x25, 0x59, 0x23, 0x04, 0x15, 0x08, 0x23];
+        let expectations = [I2cTrans::write_read(
+            0x68,
+            vec![Register::Seconds.addr()],
+            data.to_vec(),
+        )];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        let datetime = ds1307.get_primitive_datetime().unwrap();
+        assert_eq!(datetime, primitive(2023, 8, 15, 23, 59, 25));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_primitive_datetime() {
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x45,
+                0x30,
+                0x15,
+                0x04,
+                0x27,
+                0x08,
+                0x25,
+            ],
+        )];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+
+        ds1307
+            .set_primitive_datetime(&primitive(2025, 8, 27, 15, 30, 45))
+            .unwrap();
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_primitive_datetime_invalid_year() {
+        let mut ds1307 = Ds1307::new(I2cMock::new(&[]));
+
+        let result = ds1307.set_primitive_datetime(&primitive(1999, 12, 31, 23, 59, 59));
+        assert!(matches!(
+            result,
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+
+    #[test]
+    fn test_set_primitive_datetime_custom_century_base() {
+        // 1999-12-31 is a Friday (6)
+        let expectations = [I2cTrans::write(
+            0x68,
+            vec![
+                Register::Seconds.addr(),
+                0x59,
+                0x59,
+                0x23,
+                0x06,
+                0x31,
+                0x12,
+                0x29,
+            ],
+        )];
+        let mut ds1307 = Ds1307::new(I2cMock::new(&expectations));
+        ds1307.set_century_base(1970).unwrap();
+
+        ds1307
+            .set_primitive_datetime(&primitive(1999, 12, 31, 23, 59, 59))
+            .unwrap();
+
+        // 2070 falls outside the 1970-2069 window: no write
+        let result = ds1307.set_primitive_datetime(&primitive(2070, 1, 1, 0, 0, 0));
+        assert!(matches!(
+            result,
+            Err(Error::DateTime(DateTimeError::InvalidYear))
+        ));
+
+        ds1307.release_i2c().done();
+    }
+}